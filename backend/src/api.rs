@@ -1,8 +1,13 @@
-use axum::{routing::get, Json, Router};
+use axum::{extract::{Query, State}, http::StatusCode, routing::{get, post}, Json, Router};
 use std::path::Path;
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
+use crate::bus;
+use crate::db::{Message, MessageQuery, MessageType};
 use crate::fs;
+use crate::notifications::Notification;
+use crate::AppState;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FileNode {
@@ -11,8 +16,17 @@ pub struct FileNode {
     pub children: Option<Vec<FileNode>>,
 }
 
+/// Default page size for `GET /api/messages` when the caller doesn't ask
+/// for a particular `limit`.
+const DEFAULT_MESSAGE_LIMIT: usize = 100;
+
 pub fn router() -> Router<std::sync::Arc<crate::AppState>> {
-    Router::new().route("/map", get(get_project_map))
+    Router::new()
+        .route("/map", get(get_project_map))
+        .route("/notifications", get(get_notifications))
+        .route("/messages", get(get_messages).post(post_message))
+        .route("/messages/send", post(bus::send_message))
+        .route("/messages/subscribe", get(bus::subscribe_messages))
 }
 
 async fn get_project_map() -> Json<FileNode> {
@@ -24,6 +38,47 @@ async fn get_project_map() -> Json<FileNode> {
     Json(root)
 }
 
+/// Polled by the dashboard clients to render the stacked toast overlay
+async fn get_notifications(State(state): State<Arc<AppState>>) -> Json<Vec<Notification>> {
+    Json(state.notifications.snapshot().await)
+}
+
+#[derive(Deserialize)]
+struct MessagesParams {
+    receiver: Option<String>,
+    correlation_id: Option<String>,
+    msg_type: Option<MessageType>,
+    limit: Option<usize>,
+}
+
+/// Lets the log viewer reconstruct a WorkRequest→CodeOutput→Evaluation→
+/// Complete chain after a restart, by replaying the journal.
+async fn get_messages(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<MessagesParams>,
+) -> Result<Json<Vec<Message>>, StatusCode> {
+    let query = MessageQuery {
+        receiver: params.receiver,
+        correlation_id: params.correlation_id,
+        msg_type: params.msg_type,
+        limit: params.limit.unwrap_or(DEFAULT_MESSAGE_LIMIT),
+    };
+    state
+        .db
+        .query_messages(query)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// Journal one inter-agent message as it flows through the server.
+async fn post_message(State(state): State<Arc<AppState>>, Json(message): Json<Message>) -> StatusCode {
+    match state.db.insert_message(&message).await {
+        Ok(_) => StatusCode::CREATED,
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
 async fn scan_path(path: impl AsRef<Path>) -> Result<FileNode> {
     let name = path.as_ref().file_name()
         .map(|n| n.to_string_lossy().to_string())
@@ -59,25 +114,48 @@ mod tests {
         http::{Request, StatusCode},
     };
     use tower::util::ServiceExt;
-    use std::sync::Arc;
-    use crate::AppState;
+    use tokio::sync::RwLock;
+    use std::path::PathBuf;
     use crate::ollama::OllamaClient;
     use crate::db::Db;
+    use crate::notifications::NotificationStore;
+    use crate::metrics::AppMetrics;
+    use crate::telemetry::TelemetrySampler;
+    use crate::auth::AuthConfig;
 
     #[tokio::test]
     async fn test_get_map() {
-        let db = Db::new().await.unwrap();
+        std::env::set_var("RALPH_API_SECRET", "test-secret");
+        let db = Db::new_test().await.unwrap();
         let state = Arc::new(AppState {
             ollama: OllamaClient::new("http://localhost:11434".to_string()),
             db,
+            project_root: RwLock::new(PathBuf::from(".")),
+            notifications: NotificationStore::default(),
+            metrics: AppMetrics::new().unwrap(),
+            telemetry: TelemetrySampler::default(),
+            auth: AuthConfig::from_env(),
+            message_bus: crate::bus::channel(),
         });
         let app = crate::app(state);
 
+        let token = state_token();
+
         let response = app
-            .oneshot(Request::builder().uri("/api/map").body(Body::empty()).unwrap())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/map")
+                    .header("Authorization", format!("Bearer {token}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
             .await
             .unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    fn state_token() -> String {
+        AuthConfig::from_env().mint_token("test", "api").unwrap()
+    }
 }
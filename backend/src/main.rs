@@ -1,41 +1,76 @@
 use axum::{
-    extract::State,
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
     response::sse::{Event, Sse},
     routing::{get, post},
-    Json, Router,
+    Extension, Json, Router,
 };
-use futures_util::stream::Stream;
+use futures_util::stream::BoxStream;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::convert::Infallible;
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Instant;
+use tokio::sync::{broadcast, RwLock};
 use tower_http::cors::{Any, CorsLayer};
+use tracing::Instrument;
 
 mod api;
+mod auth;
+mod bus;
 mod db;
 mod fs;
+mod metrics;
+mod notifications;
 mod ollama;
+mod telemetry;
+mod tracing_setup;
+use auth::{AuthConfig, RequiredScope};
 use db::Db;
+use metrics::AppMetrics;
+use notifications::{Notification, NotificationStore, Severity};
 use ollama::OllamaClient;
+use telemetry::TelemetrySampler;
+
+/// Cheap monotonic id for correlating a `/chat` request across its SSE
+/// stream and the trace spans it emits; not a UUID since nothing else in
+/// this crate needs collision-resistance across restarts.
+fn next_correlation_id() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    format!("chat-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+}
 
 pub struct AppState {
     pub ollama: OllamaClient,
     pub db: Db,
     pub project_root: RwLock<PathBuf>,
+    pub notifications: NotificationStore,
+    pub metrics: AppMetrics,
+    pub telemetry: TelemetrySampler,
+    pub auth: AuthConfig,
+    pub message_bus: broadcast::Sender<db::Message>,
 }
 
 #[tokio::main]
 async fn main() {
+    tracing_setup::init();
+
     let db = Db::new().await.unwrap();
     let project_root = RwLock::new(std::env::current_dir().unwrap());
-    
+
     let state = Arc::new(AppState {
         ollama: OllamaClient::new("http://localhost:11434".to_string()),
         db,
         project_root,
+        notifications: NotificationStore::default(),
+        metrics: AppMetrics::new().expect("failed to register Prometheus collectors"),
+        telemetry: TelemetrySampler::default(),
+        auth: AuthConfig::from_env(),
+        message_bus: bus::channel(),
     });
 
     let app = app(state);
@@ -52,19 +87,50 @@ pub fn app(state: Arc<AppState>) -> Router {
         .allow_methods(Any)
         .allow_headers(Any);
 
+    // `/chat` and `/api` carry a bearer-token check, each requiring a
+    // token minted for its own scope (an "admin"-scoped token satisfies
+    // either); `/health`, `/metrics`, and `/auth/token` stay open so
+    // health checks, scrapes, and the bootstrapping token request itself
+    // don't need a token already. The `Extension` layer has to be added
+    // *after* `route_layer` so it wraps outside it and runs first,
+    // putting `RequiredScope` in the request before `require_token` looks
+    // for it.
+    let chat_routes = Router::new()
+        .route("/chat", get(chat))
+        .route_layer(middleware::from_fn_with_state(state.clone(), auth::require_token))
+        .layer(Extension(RequiredScope("chat")));
+
+    let api_routes = Router::new()
+        .nest("/api", api::router())
+        .route_layer(middleware::from_fn_with_state(state.clone(), auth::require_token))
+        .layer(Extension(RequiredScope("api")));
+
     Router::new()
         .route("/health", get(health_check))
         .route("/telemetry", get(telemetry))
-        .route("/chat", post(chat))
-        .nest("/api", api::router())
+        .route("/metrics", get(metrics_handler))
+        .route("/auth/token", post(auth::issue_token))
+        .merge(chat_routes)
+        .merge(api_routes)
         .layer(cors)
         .with_state(state)
 }
 
-async fn health_check() -> &'static str {
+async fn health_check(State(state): State<Arc<AppState>>) -> &'static str {
+    state.metrics.record_response("/health", StatusCode::OK);
     "OK"
 }
 
+/// Prometheus scrape endpoint, driven by the same `AppMetrics` registry
+/// every other handler in this server increments. The `ralph_gui`
+/// sidebar keeps its own `Metrics` and does not read this registry.
+async fn metrics_handler(State(state): State<Arc<AppState>>) -> (StatusCode, [(&'static str, &'static str); 1], String) {
+    match state.metrics.encode() {
+        Ok(body) => (StatusCode::OK, [("content-type", "text/plain; version=0.0.4")], body),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, [("content-type", "text/plain; version=0.0.4")], String::new()),
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct Telemetry {
     cpu_usage: f32,
@@ -72,11 +138,13 @@ struct Telemetry {
     gpu_usage: Option<f32>,
 }
 
-async fn telemetry() -> Json<Telemetry> {
+async fn telemetry(State(state): State<Arc<AppState>>) -> Json<Telemetry> {
+    state.metrics.record_response("/telemetry", StatusCode::OK);
+    let sample = state.telemetry.sample().await;
     Json(Telemetry {
-        cpu_usage: 10.5,
-        memory_usage: 45.0,
-        gpu_usage: Some(15.0),
+        cpu_usage: sample.cpu_usage,
+        memory_usage: sample.memory_usage,
+        gpu_usage: sample.gpu_usage,
     })
 }
 
@@ -86,29 +154,80 @@ struct ChatRequest {
     prompt: String,
 }
 
+/// One chunk of a streamed chat reply, as delivered to the browser.
+///
+/// `done` lets the client know to stop appending and close the
+/// `EventSource`, since a normally-closed SSE response otherwise just
+/// looks like a connection the browser should retry.
+#[derive(Serialize)]
+struct ChatToken {
+    token: String,
+    done: bool,
+}
+
+/// Streams the model's reply one token at a time as Server-Sent Events.
+///
+/// `GET` (rather than `POST`) so browsers can drive this with `EventSource`,
+/// which only speaks `GET`. The span this carries (`model`,
+/// `correlation_id`) is entered again for each streamed chunk so
+/// per-token/per-iteration events land under the same trace even though
+/// the stream is polled after the instrumented function body returns.
+/// Generation failures — the request never starting, or a chunk coming
+/// back as an error mid-stream — also surface as a [`Notification`] so
+/// the dashboards' toast overlays actually have something to show.
+#[tracing::instrument(skip(state, payload), fields(model = %payload.model, correlation_id = tracing::field::Empty))]
 async fn chat(
     State(state): State<Arc<AppState>>,
-    Json(payload): Json<ChatRequest>,
-) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let stream = state
-        .ollama
-        .generate_stream(payload.model, payload.prompt)
-        .await
-        .unwrap();
-
-    let sse_stream = stream.map(|result| {
-        match result {
-            Ok(bytes) => {
-                let text = String::from_utf8_lossy(&bytes).to_string();
-                Ok(Event::default().data(text))
-            }
-            Err(e) => {
-                Ok(Event::default().event("error").data(e.to_string()))
+    Query(payload): Query<ChatRequest>,
+) -> Sse<BoxStream<'static, Result<Event, Infallible>>> {
+    let correlation_id = next_correlation_id();
+    tracing::Span::current().record("correlation_id", &correlation_id);
+
+    state.metrics.record_response("/chat", StatusCode::OK);
+    let started_at = Instant::now();
+    let span = tracing::Span::current();
+
+    let stream = match state.ollama.generate_stream(payload.model, payload.prompt).await {
+        Ok(stream) => stream,
+        Err(e) => {
+            state
+                .notifications
+                .push(Notification::new(Severity::Error, "Chat generation failed to start", e.to_string()))
+                .await;
+            let chat_token = ChatToken { token: format!("[error: {e}]"), done: true };
+            let error_event = Ok(Event::default().json_data(chat_token).unwrap_or_else(|_| Event::default().data("")));
+            return Sse::new(futures_util::stream::once(async move { error_event }).boxed());
+        }
+    };
+
+    let sse_stream = stream.then(move |result| {
+        let state = state.clone();
+        async move {
+            let chat_token = match result {
+                Ok(chunk) => ChatToken { token: chunk.response, done: chunk.done },
+                Err(e) => {
+                    state
+                        .notifications
+                        .push(Notification::new(Severity::Error, "Chat generation failed", e.to_string()))
+                        .await;
+                    ChatToken { token: format!("[error: {e}]"), done: true }
+                }
+            };
+
+            state.metrics.tokens_total.inc();
+            tracing::trace!(tokens = 1, "chat token streamed");
+            if chat_token.done {
+                state.metrics.iterations_total.inc();
+                state.metrics.request_duration.observe(started_at.elapsed().as_secs_f64());
+                tracing::info!(elapsed_ms = started_at.elapsed().as_millis() as u64, "chat generation complete");
             }
+
+            Ok(Event::default().json_data(chat_token).unwrap_or_else(|_| Event::default().data("")))
         }
+        .instrument(span.clone())
     });
 
-    Sse::new(sse_stream)
+    Sse::new(sse_stream.boxed())
 }
 
 #[cfg(test)]
@@ -121,11 +240,17 @@ mod tests {
     use tower::util::ServiceExt;
 
     async fn test_app() -> Router {
-        let db = Db::new().await.unwrap();
+        std::env::set_var("RALPH_API_SECRET", "test-secret");
+        let db = Db::new_test().await.unwrap();
         let state = Arc::new(AppState {
             ollama: OllamaClient::new("http://localhost:11434".to_string()),
             db,
             project_root: RwLock::new(PathBuf::from(".")),
+            notifications: NotificationStore::default(),
+            metrics: AppMetrics::new().unwrap(),
+            telemetry: TelemetrySampler::default(),
+            auth: AuthConfig::from_env(),
+            message_bus: bus::channel(),
         });
         app(state)
     }
@@ -0,0 +1,66 @@
+//! Prometheus metrics shared across requests via `AppState`
+//!
+//! Collectors live on a single `Registry` so `/metrics` and anything
+//! that increments a counter always agree.
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use prometheus::{Encoder, Histogram, HistogramOpts, IntCounter, IntCounterVec, Opts, Registry, TextEncoder};
+
+/// Streamed chat completions range from sub-second to about a minute,
+/// so the buckets span 50ms to 60s.
+const DURATION_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+#[derive(Clone)]
+pub struct AppMetrics {
+    registry: Registry,
+    /// Tokens streamed back to clients over `/chat`.
+    pub tokens_total: IntCounter,
+    /// Completed `/chat` generations, i.e. one agent loop iteration each.
+    pub iterations_total: IntCounter,
+    /// Wall-clock time of a `/chat` generation, from the request to its
+    /// final streamed chunk. Not labelled; no other route observes it.
+    pub request_duration: Histogram,
+    /// Responses by route and status class. There is no counter keyed by
+    /// `LogLevel`.
+    pub responses_total: IntCounterVec,
+}
+
+impl AppMetrics {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+
+        let tokens_total = IntCounter::with_opts(Opts::new("tokens_total", "Total tokens streamed to clients"))?;
+        let iterations_total = IntCounter::with_opts(Opts::new("iterations_total", "Total completed chat generations"))?;
+        let request_duration = Histogram::with_opts(
+            HistogramOpts::new("request_duration_seconds", "Request handling duration in seconds")
+                .buckets(DURATION_BUCKETS.to_vec()),
+        )?;
+        let responses_total = IntCounterVec::new(
+            Opts::new("responses_total", "Responses served, by route and status class"),
+            &["route", "status"],
+        )?;
+
+        registry.register(Box::new(tokens_total.clone()))?;
+        registry.register(Box::new(iterations_total.clone()))?;
+        registry.register(Box::new(request_duration.clone()))?;
+        registry.register(Box::new(responses_total.clone()))?;
+
+        Ok(Self { registry, tokens_total, iterations_total, request_duration, responses_total })
+    }
+
+    /// Record that `route` answered with `status`.
+    pub fn record_response(&self, route: &str, status: StatusCode) {
+        let class = format!("{}xx", status.as_u16() / 100);
+        self.responses_total.with_label_values(&[route, &class]).inc();
+    }
+
+    /// Render every registered collector in Prometheus text exposition
+    /// format.
+    pub fn encode(&self) -> Result<String> {
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&metric_families, &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
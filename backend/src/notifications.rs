@@ -0,0 +1,68 @@
+//! In-memory notification store backing `GET /api/notifications`
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+/// Maximum number of notifications retained in the ring buffer
+const MAX_NOTIFICATIONS: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: u64,
+    pub severity: Severity,
+    pub title: String,
+    pub body: String,
+    pub timestamp: String,
+}
+
+impl Notification {
+    /// Build a notification stamped with a fresh id and the current time,
+    /// ready to hand to [`NotificationStore::push`].
+    pub fn new(severity: Severity, title: impl Into<String>, body: impl Into<String>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+            .to_string();
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            severity,
+            title: title.into(),
+            body: body.into(),
+            timestamp,
+        }
+    }
+}
+
+/// Bounded ring buffer shared across requests via `AppState`
+#[derive(Default)]
+pub struct NotificationStore {
+    entries: RwLock<VecDeque<Notification>>,
+}
+
+impl NotificationStore {
+    pub async fn push(&self, notification: Notification) {
+        let mut entries = self.entries.write().await;
+        entries.push_back(notification);
+        if entries.len() > MAX_NOTIFICATIONS {
+            entries.pop_front();
+        }
+    }
+
+    pub async fn snapshot(&self) -> Vec<Notification> {
+        self.entries.read().await.iter().cloned().collect()
+    }
+}
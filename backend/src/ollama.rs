@@ -1,8 +1,6 @@
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
-use futures_util::stream::BoxStream;
-use futures_util::StreamExt;
-use bytes::Bytes;
+use futures_util::stream::{BoxStream, StreamExt};
 
 pub struct OllamaClient {
     base_url: String,
@@ -48,7 +46,13 @@ impl OllamaClient {
         Ok(response)
     }
 
-    pub async fn generate_stream(&self, model: String, prompt: String) -> Result<BoxStream<'static, Result<Bytes, reqwest::Error>>> {
+    /// Stream a generation one Ollama NDJSON chunk (i.e. token) at a time.
+    ///
+    /// Ollama's `stream: true` response is newline-delimited JSON, but HTTP
+    /// chunk boundaries don't line up with those newlines, so this buffers
+    /// partial lines across chunks rather than handing raw bytes upstream.
+    #[tracing::instrument(skip(self, prompt), fields(model = %model))]
+    pub async fn generate_stream(&self, model: String, prompt: String) -> Result<BoxStream<'static, Result<GenerateResponse>>> {
         let url = format!("{}/api/generate", self.base_url);
         let request = GenerateRequest {
             model,
@@ -61,7 +65,39 @@ impl OllamaClient {
             .send()
             .await?;
 
-        Ok(response.bytes_stream().boxed())
+        let bytes_stream = response.bytes_stream();
+        let stream = futures_util::stream::unfold(
+            (bytes_stream, String::new()),
+            |(mut bytes_stream, mut buffer)| async move {
+                loop {
+                    if let Some(pos) = buffer.find('\n') {
+                        let line = buffer[..pos].trim().to_string();
+                        buffer.drain(..=pos);
+                        if line.is_empty() {
+                            continue;
+                        }
+                        let parsed = serde_json::from_str(&line).map_err(anyhow::Error::from);
+                        return Some((parsed, (bytes_stream, buffer)));
+                    }
+
+                    match bytes_stream.next().await {
+                        Some(Ok(chunk)) => buffer.push_str(&String::from_utf8_lossy(&chunk)),
+                        Some(Err(e)) => return Some((Err(anyhow::Error::from(e)), (bytes_stream, buffer))),
+                        None => {
+                            let remainder = buffer.trim().to_string();
+                            if remainder.is_empty() {
+                                return None;
+                            }
+                            buffer.clear();
+                            let parsed = serde_json::from_str(&remainder).map_err(anyhow::Error::from);
+                            return Some((parsed, (bytes_stream, buffer)));
+                        }
+                    }
+                }
+            },
+        );
+
+        Ok(stream.boxed())
     }
 }
 
@@ -81,4 +117,11 @@ mod tests {
         let result = client.generate("llama3", "hello").await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_generate_stream_failure() {
+        let client = OllamaClient::new("http://localhost:11111".to_string());
+        let result = client.generate_stream("llama3".to_string(), "hello".to_string()).await;
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,103 @@
+//! Host/GPU telemetry sampling backing the `/telemetry` route
+//!
+//! Wraps a `sysinfo::System` behind a mutex so concurrent pollers share
+//! one sampler, and only actually re-scans the host when
+//! `MIN_REFRESH_INTERVAL` has elapsed rather than on every request.
+
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use sysinfo::System;
+
+/// Floor on how often we re-scan CPU/memory; `sysinfo` docs recommend
+/// not refreshing faster than this for stable readings.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct HostTelemetry {
+    pub cpu_usage: f32,
+    pub memory_usage: f32,
+    pub gpu_usage: Option<f32>,
+}
+
+pub struct TelemetrySampler {
+    system: Mutex<System>,
+    last_refresh: Mutex<Option<Instant>>,
+}
+
+impl Default for TelemetrySampler {
+    fn default() -> Self {
+        Self { system: Mutex::new(System::new()), last_refresh: Mutex::new(None) }
+    }
+}
+
+impl TelemetrySampler {
+    /// Sample current host load, refreshing the underlying `System`
+    /// first if it's been at least `MIN_REFRESH_INTERVAL` since the
+    /// last refresh.
+    ///
+    /// GPU sampling shells out to `nvidia-smi`/reads `/sys`, which can
+    /// block for an arbitrary amount of time (e.g. a wedged driver), so
+    /// it runs on a blocking-pool thread rather than a Tokio worker, and
+    /// after the CPU/memory mutex has already been released.
+    pub async fn sample(&self) -> HostTelemetry {
+        let (cpu_usage, memory_usage) = {
+            let mut last_refresh = self.last_refresh.lock().unwrap();
+            let should_refresh = last_refresh.is_none_or(|t| t.elapsed() >= MIN_REFRESH_INTERVAL);
+
+            let mut system = self.system.lock().unwrap();
+            if should_refresh {
+                system.refresh_cpu_usage();
+                system.refresh_memory();
+                *last_refresh = Some(Instant::now());
+            }
+
+            let memory_usage = if system.total_memory() > 0 {
+                system.used_memory() as f32 / system.total_memory() as f32 * 100.0
+            } else {
+                0.0
+            };
+
+            (system.global_cpu_usage(), memory_usage)
+        };
+
+        let gpu_usage = tokio::task::spawn_blocking(sample_gpu_usage).await.unwrap_or(None);
+
+        HostTelemetry { cpu_usage, memory_usage, gpu_usage }
+    }
+}
+
+/// Best-effort GPU utilization: try NVIDIA's `nvidia-smi` first, then
+/// AMD's `gpu_busy_percent` sysfs counter. `None` when neither is
+/// present, rather than a made-up percentage.
+fn sample_gpu_usage() -> Option<f32> {
+    sample_nvidia_gpu_usage().or_else(sample_amd_gpu_usage)
+}
+
+fn sample_nvidia_gpu_usage() -> Option<f32> {
+    let output = Command::new("nvidia-smi")
+        .args(["--query-gpu=utilization.gpu", "--format=csv,noheader,nounits"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()?.lines().next()?.trim().parse().ok()
+}
+
+/// AMD GPUs expose a `gpu_busy_percent` file per card under
+/// `/sys/class/drm/cardN/device/`; read the first card that has one.
+fn sample_amd_gpu_usage() -> Option<f32> {
+    let entries = std::fs::read_dir("/sys/class/drm").ok()?;
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.starts_with("card") || name.contains('-') {
+            continue;
+        }
+        let path = entry.path().join("device/gpu_busy_percent");
+        if let Some(value) = std::fs::read_to_string(path).ok().and_then(|s| s.trim().parse().ok()) {
+            return Some(value);
+        }
+    }
+    None
+}
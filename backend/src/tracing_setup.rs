@@ -0,0 +1,46 @@
+//! Tracing/OTLP pipeline setup
+//!
+//! Layers a plain terminal formatter with an OTLP batch exporter when
+//! `OTEL_EXPORTER_OTLP_ENDPOINT` is set, so spans on `/chat` and
+//! `OllamaClient::generate_stream` show up in whatever collector that
+//! endpoint points at, correlated by the `correlation_id` field each span
+//! carries.
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// Install the global subscriber. Call once from `main()` before
+/// anything else logs.
+pub fn init() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let fmt_layer = tracing_subscriber::fmt::layer();
+    let otlp_layer = build_otlp_layer();
+
+    Registry::default()
+        .with(filter)
+        .with(fmt_layer)
+        .with(otlp_layer)
+        .init();
+}
+
+/// `None` when `OTEL_EXPORTER_OTLP_ENDPOINT` isn't set, so running
+/// without a collector configured is just a quieter local setup, not an
+/// error.
+fn build_otlp_layer<S>() -> Option<tracing_opentelemetry::OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>>
+where
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+{
+    let endpoint = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok()?;
+
+    let exporter = opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint);
+    let provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter)
+        .install_batch(opentelemetry_sdk::runtime::Tokio)
+        .expect("failed to install OTLP tracer");
+
+    Some(tracing_opentelemetry::layer().with_tracer(provider.tracer("ralph-backend")))
+}
@@ -1,13 +1,38 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use serde::{Deserialize, Serialize};
-#[allow(unused_imports)]
 use gloo_net::eventsource::futures::EventSource;
-#[allow(unused_imports)]
-use futures::StreamExt;
+use futures::stream::StreamExt;
 use gloo_net::http::Request;
 use wasm_bindgen::JsCast; // Added this import!
 
+/// Default model, overridable at build time with `RALPH_MODEL`.
+const DEFAULT_MODEL: &str = match option_env!("RALPH_MODEL") {
+    Some(model) => model,
+    None => "qwen3:8b",
+};
+
+/// Default backend base URL, overridable at build time with `RALPH_SERVER_URL`.
+const DEFAULT_SERVER_URL: &str = match option_env!("RALPH_SERVER_URL") {
+    Some(url) => url,
+    None => "http://127.0.0.1:3000",
+};
+
+/// Client secret presented to `POST /auth/token` so the frontend can mint
+/// its own bearer tokens, overridable at build time with `RALPH_API_TOKEN`.
+/// The minted token itself is *not* baked in: `auth::TOKEN_TTL` is 15
+/// minutes, far shorter than a page stays open, so the actual bearer
+/// token is fetched at runtime and refreshed before it expires (see
+/// `fetch_chat_token` and its refresh loop in `App`).
+const CLIENT_SECRET: &str = match option_env!("RALPH_API_TOKEN") {
+    Some(secret) => secret,
+    None => "",
+};
+
+/// Refresh the chat token this many seconds before its reported expiry,
+/// so a request already in flight doesn't race the old token going stale.
+const TOKEN_REFRESH_MARGIN_SECS: u64 = 30;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 struct ChatMessage {
     role: String,
@@ -50,9 +75,82 @@ fn FileTree(node: FileNode) -> impl IntoView {
     }
 }
 
+/// Mirrors the backend's `ChatToken` — one chunk of a streamed reply.
 #[derive(Deserialize)]
-struct ChatResponse {
-    response: String,
+struct ChatToken {
+    token: String,
+    done: bool,
+}
+
+/// Mirrors the backend's `auth::TokenResponse`.
+#[derive(Deserialize)]
+struct TokenResponse {
+    token: String,
+    expires_in: u64,
+}
+
+/// Mint a fresh `scope: "chat"` bearer token via `POST /auth/token`,
+/// authenticating the request itself with `CLIENT_SECRET`.
+async fn fetch_chat_token() -> Option<TokenResponse> {
+    let request = Request::post(&format!("{}/auth/token", DEFAULT_SERVER_URL))
+        .header("X-Api-Secret", CLIENT_SECRET)
+        .json(&serde_json::json!({ "sub": "frontend", "scope": "chat" }))
+        .ok()?;
+    let response = request.send().await.ok()?;
+    response.json::<TokenResponse>().await.ok()
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct Notification {
+    id: u64,
+    severity: String,
+    title: String,
+    body: String,
+    timestamp: String,
+}
+
+/// Polls `/api/notifications` and renders a stacked toast overlay
+#[component]
+fn NotificationsOverlay() -> impl IntoView {
+    let (notifications, set_notifications) = signal(Vec::<Notification>::new());
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            loop {
+                if let Ok(resp) = Request::get(&format!("{}/api/notifications", DEFAULT_SERVER_URL))
+                    .send()
+                    .await
+                {
+                    if let Ok(list) = resp.json::<Vec<Notification>>().await {
+                        set_notifications.set(list);
+                    }
+                }
+                gloo_timers::future::TimeoutFuture::new(3_000).await;
+            }
+        });
+    });
+
+    view! {
+        <div class="notifications-overlay" style="position: fixed; bottom: 16px; right: 16px; display: flex; flex-direction: column-reverse; gap: 8px; z-index: 1000;">
+            <For
+                each=move || notifications.get()
+                key=|n| n.id
+                children=|n| {
+                    let border = match n.severity.as_str() {
+                        "error" => "#f44336",
+                        "warning" => "#ffc107",
+                        _ => "#7b2cbf",
+                    };
+                    view! {
+                        <div style=format!("background: #16213e; color: #eaeaea; border-left: 3px solid {}; padding: 10px 14px; max-width: 280px; font-family: monospace; font-size: 0.85rem;", border)>
+                            <strong>{n.title.clone()}</strong>
+                            <div style="color: #969696aa;">{n.body.clone()}</div>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
 }
 
 #[component]
@@ -60,6 +158,25 @@ pub fn App() -> impl IntoView {
     let (input, set_input) = signal(String::new());
     let (messages, set_messages) = signal(Vec::<ChatMessage>::new());
     let (is_loading, set_is_loading) = signal(false);
+    let (chat_token, set_chat_token) = signal(String::new());
+
+    // Mint a chat token on load, then keep re-minting it shortly before
+    // each one expires so the stream doesn't start failing partway
+    // through a long-lived page.
+    Effect::new(move |_| {
+        spawn_local(async move {
+            loop {
+                let wait_secs = match fetch_chat_token().await {
+                    Some(token_resp) => {
+                        set_chat_token.set(token_resp.token);
+                        token_resp.expires_in.saturating_sub(TOKEN_REFRESH_MARGIN_SECS).max(1)
+                    }
+                    None => 5,
+                };
+                gloo_timers::future::TimeoutFuture::new((wait_secs * 1000) as u32).await;
+            }
+        });
+    });
 
     let send_message = move || {
         let current_input = input.get();
@@ -79,45 +196,85 @@ pub fn App() -> impl IntoView {
         set_input.set(String::new());
         set_is_loading.set(true);
 
-        spawn_local(async move {
-            let payload = serde_json::json!({
-                "model": "qwen3:8b",
-                "prompt": prompt
+        // Streamed token-by-token: push an empty reply now, then append to
+        // it as `token` events arrive over the `/chat` SSE connection.
+        set_messages.update(|msgs| {
+            msgs.push(ChatMessage {
+                role: "TWAI".to_string(),
+                content: String::new(),
             });
+        });
 
-            match Request::post("http://127.0.0.1:3000/chat")
-                .json(&payload)
-                .unwrap()
-                .send()
-                .await
-            {
-                Ok(resp) => {
-                    if let Ok(data) = resp.json::<ChatResponse>().await {
-                        set_messages.update(|msgs| {
-                            msgs.push(ChatMessage {
-                                role: "TWAI".to_string(),
-                                content: data.response,
-                            });
-                        });
-                    } else {
-                        set_messages.update(|msgs| {
-                            msgs.push(ChatMessage {
-                                role: "System".to_string(),
-                                content: "Error parsing response".to_string(),
-                            });
+        let token = chat_token.get_untracked();
+        spawn_local(async move {
+            let url = format!(
+                "{}/chat?model={}&prompt={}&token={}",
+                DEFAULT_SERVER_URL,
+                js_sys::encode_uri_component(DEFAULT_MODEL),
+                js_sys::encode_uri_component(&prompt),
+                js_sys::encode_uri_component(&token),
+            );
+
+            let mut source = match EventSource::new(&url) {
+                Ok(source) => source,
+                Err(e) => {
+                    set_messages.update(|msgs| {
+                        msgs.push(ChatMessage {
+                            role: "System".to_string(),
+                            content: format!("Error opening chat stream: {:?}", e),
                         });
-                    }
+                    });
+                    set_is_loading.set(false);
+                    return;
                 }
+            };
+
+            let mut events = match source.subscribe("message") {
+                Ok(events) => events,
                 Err(e) => {
                     set_messages.update(|msgs| {
                         msgs.push(ChatMessage {
                             role: "System".to_string(),
-                            content: format!("Network Error: {:?}", e),
+                            content: format!("Error subscribing to chat stream: {:?}", e),
                         });
                     });
+                    set_is_loading.set(false);
+                    return;
+                }
+            };
+
+            while let Some(event) = events.next().await {
+                let mut done = true;
+                match event {
+                    Ok((_, msg)) => {
+                        let parsed = msg
+                            .data()
+                            .as_string()
+                            .and_then(|data| serde_json::from_str::<ChatToken>(&data).ok());
+                        if let Some(chat_token) = parsed {
+                            done = chat_token.done;
+                            set_messages.update(|msgs| {
+                                if let Some(last) = msgs.last_mut() {
+                                    last.content.push_str(&chat_token.token);
+                                }
+                            });
+                        }
+                    }
+                    Err(e) => {
+                        set_messages.update(|msgs| {
+                            msgs.push(ChatMessage {
+                                role: "System".to_string(),
+                                content: format!("Chat stream error: {:?}", e),
+                            });
+                        });
+                    }
+                }
+                if done {
+                    break;
                 }
             }
-            
+
+            source.close();
             set_is_loading.set(false);
         });
     };
@@ -174,6 +331,10 @@ fn main() {
         leptos::mount::mount_to(el.unchecked_into(), App).forget();
     }
 
+    if let Some(el) = doc.get_element_by_id("notifications-placeholder") {
+        leptos::mount::mount_to(el.unchecked_into(), NotificationsOverlay).forget();
+    }
+
     if let Some(el) = doc.get_element_by_id("map-placeholder") {
         leptos::mount::mount_to(el.unchecked_into(), || {
             let (project_map, set_project_map) = signal(None::<FileNode>);
@@ -183,7 +344,7 @@ fn main() {
             let fetch_map = move || {
                 spawn_local(async move {
                     set_status_msg.set("Fetching map...".to_string());
-                    match Request::get("http://127.0.0.1:3000/api/map").send().await {
+                    match Request::get(&format!("{}/api/map", DEFAULT_SERVER_URL)).send().await {
                         Ok(resp) => {
                             if let Ok(map) = resp.json::<FileNode>().await {
                                 set_project_map.set(Some(map));
@@ -203,7 +364,7 @@ fn main() {
                 let path = path_input.get();
                 set_status_msg.set(format!("Setting root to: {}...", path));
                 spawn_local(async move {
-                    match Request::post("http://127.0.0.1:3000/api/set_root")
+                    match Request::post(&format!("{}/api/set_root", DEFAULT_SERVER_URL))
                         .json(&serde_json::json!({ "path": path }))
                         .unwrap()
                         .send()
@@ -1,12 +1,19 @@
 //! Main application state and update loop
 
 use eframe::egui;
-use std::collections::VecDeque;
+use chrono::Local;
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use crossbeam_channel::{Receiver, Sender, unbounded};
 use serde_json::Value;
 
-use crate::ralph::{RalphRunner, AgentState, Metrics, LogEntry};
+use crate::config::CompleteConfig;
+use crate::graph::AgentGraph;
+use crate::notifications::{Notification, NotificationBuffer, Severity};
+use crate::ralph::{RalphRunner, AgentState, Metrics, LogEntry, LogLevel, Message, MessageType, LogSource, ConnectedSource};
+use crate::search::SearchIndex;
+use crate::tokenizer::CoreBpe;
 use crate::ui;
 use crate::theme;
 
@@ -14,7 +21,7 @@ use crate::theme;
 const MAX_LOG_ENTRIES: usize = 500;
 
 /// Agent names in the swarm
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Agent {
     Translator,
     Orchestrator,
@@ -43,6 +50,27 @@ impl Agent {
             Agent::Asic => "ASICs",
         }
     }
+
+    /// Parse a `Message::sender`/`receiver` string (e.g. `"orchestrator"`)
+    /// into the matching agent, case-insensitively. Returns `None` for
+    /// non-agent participants like `"gui"` or `"system"`.
+    pub fn parse(name: &str) -> Option<Agent> {
+        match name.to_ascii_lowercase().as_str() {
+            "translator" => Some(Agent::Translator),
+            "orchestrator" => Some(Agent::Orchestrator),
+            "engineer" => Some(Agent::Engineer),
+            "designer" => Some(Agent::Designer),
+            "asic" | "asics" => Some(Agent::Asic),
+            _ => None,
+        }
+    }
+}
+
+/// Per-agent sampling parameters shown on the Control Center sliders
+#[derive(Debug, Clone, Copy)]
+pub struct AgentParams {
+    pub temperature: f32,
+    pub top_p: f32,
 }
 
 /// Task in the plan
@@ -64,14 +92,23 @@ pub enum TaskStatus {
 pub struct RalphApp {
     // Input
     pub objective_input: String,
+    /// `host:port` typed into the "connect to host" field, for attaching
+    /// as a read-only follower to someone else's run.
+    pub host_input: String,
 
     // Agent states
     pub agent_states: std::collections::HashMap<Agent, AgentState>,
-    pub active_connection: Option<(Agent, Agent)>,
+    /// Edge/activity state for the force-graph, folded from the typed
+    /// agent message bus (see [`crate::graph::AgentGraph`]).
+    pub agent_graph: AgentGraph,
 
     // Logs
     pub logs: VecDeque<LogEntry>,
-    pub show_system_logs: bool,
+    /// Levels currently shown by the log viewer's toggle chips; a level
+    /// missing from this set is filtered out of `ui::logs::show`.
+    pub visible_log_levels: HashSet<LogLevel>,
+    /// Case-insensitive substring filter applied to `entry.message`.
+    pub log_filter_query: String,
 
     // Thinking
     pub current_thought: String,
@@ -83,41 +120,114 @@ pub struct RalphApp {
     // Tasks
     pub tasks: Vec<Task>,
 
+    // Control Center state
+    pub is_paused: bool,
+    pub sandbox_enabled: bool,
+    pub enabled_agents: std::collections::HashMap<Agent, bool>,
+    pub agent_params: std::collections::HashMap<Agent, AgentParams>,
+
+    // Notifications
+    pub notifications: NotificationBuffer,
+
+    // Config
+    config: CompleteConfig,
+
     // Runner
-    runner: Option<RalphRunner>,
+    /// Whatever is currently feeding `log_receiver`/`msg_receiver`: a
+    /// locally spawned `RalphRunner`, or a `ConnectedSource` following
+    /// another instance's run. `process_messages` doesn't care which.
+    runner: Option<Box<dyn LogSource>>,
     log_receiver: Option<Receiver<LogEntry>>,
+    msg_receiver: Option<Receiver<Message>>,
+    notif_receiver: Option<Receiver<Notification>>,
 
     // Animation state
     pub animation_time: f32,
+
+    /// BPE tokenizer used to estimate per-agent token/cost metering.
+    /// Built once so `process_messages` only re-runs the merge table
+    /// against new text, never re-loads it per frame.
+    tokenizer: CoreBpe,
+
+    // Search
+    /// Embedded index of everything that's passed through `add_log`,
+    /// keyed by a stable log id rather than `logs`'s live position.
+    search_index: SearchIndex,
+    /// Number of log entries evicted from the front of `logs` so far;
+    /// `log_base_offset + position` recovers a live entry's stable id.
+    log_base_offset: usize,
+    pub search_query: String,
+    /// Stable log id of the entry a search result jump should
+    /// scroll/highlight to in `ui::logs`.
+    pub highlighted_log: Option<usize>,
+
+    /// Whether the header bell's notification history panel is open.
+    pub show_notification_center: bool,
 }
 
-impl Default for RalphApp {
-    fn default() -> Self {
+impl RalphApp {
+    fn with_config(config: CompleteConfig) -> Self {
         let mut agent_states = std::collections::HashMap::new();
+        let mut enabled_agents = std::collections::HashMap::new();
+        let mut agent_params = std::collections::HashMap::new();
         for agent in Agent::all() {
             agent_states.insert(*agent, AgentState::Idle);
+            enabled_agents.insert(*agent, true);
+            agent_params.insert(
+                *agent,
+                AgentParams {
+                    temperature: config.agents.temperature_range.1.min(config.model.temperature),
+                    top_p: config.agents.top_p_range.1.min(config.model.top_p),
+                },
+            );
         }
 
         Self {
             objective_input: String::new(),
+            host_input: String::new(),
             agent_states,
-            active_connection: None,
+            agent_graph: AgentGraph::default(),
             logs: VecDeque::new(),
-            show_system_logs: false,
+            // System logs start hidden, matching the old single checkbox's
+            // default; every other level starts visible.
+            visible_log_levels: [LogLevel::Info, LogLevel::Agent, LogLevel::Error, LogLevel::Success, LogLevel::Thought]
+                .into_iter()
+                .collect(),
+            log_filter_query: String::new(),
             current_thought: String::new(),
             is_thinking: false,
             metrics: Metrics::default(),
             tasks: Vec::new(),
+            is_paused: false,
+            sandbox_enabled: false,
+            enabled_agents,
+            agent_params,
+            notifications: NotificationBuffer::default(),
+            config,
             runner: None,
             log_receiver: None,
+            msg_receiver: None,
+            notif_receiver: None,
             animation_time: 0.0,
+            tokenizer: CoreBpe::default(),
+            search_index: SearchIndex::default(),
+            log_base_offset: 0,
+            search_query: String::new(),
+            highlighted_log: None,
+            show_notification_center: false,
         }
     }
 }
 
+impl Default for RalphApp {
+    fn default() -> Self {
+        Self::with_config(CompleteConfig::default())
+    }
+}
+
 impl RalphApp {
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
-        Self::default()
+    pub fn new(_cc: &eframe::CreationContext<'_>, config: CompleteConfig) -> Self {
+        Self::with_config(config)
     }
 
     /// Start a new Ralph run with the given objective
@@ -130,31 +240,195 @@ impl RalphApp {
         for state in self.agent_states.values_mut() {
             *state = AgentState::Idle;
         }
-        self.active_connection = None;
+        self.agent_graph.clear();
+        self.search_index.clear();
+        self.log_base_offset = 0;
+        self.highlighted_log = None;
 
         // Create runner
-        let (sender, receiver) = unbounded();
-        self.log_receiver = Some(receiver);
-
-        let runner = RalphRunner::new(objective, sender);
+        let (log_sender, log_receiver) = unbounded();
+        let (msg_sender, msg_receiver) = unbounded();
+        let (notif_sender, notif_receiver) = unbounded();
+        self.log_receiver = Some(log_receiver);
+        self.msg_receiver = Some(msg_receiver);
+        self.notif_receiver = Some(notif_receiver);
+        crate::ralph::set_tracing_sender(log_sender.clone());
+
+        let runner = RalphRunner::new(
+            objective,
+            self.config.clone(),
+            self.sandbox_enabled,
+            log_sender,
+            msg_sender,
+            notif_sender,
+        );
         if let Err(e) = runner.start() {
             self.add_log(LogEntry::error(format!("Failed to start: {}", e)));
         } else {
-            self.runner = Some(runner);
+            self.runner = Some(Box::new(runner));
             self.add_log(LogEntry::system("Starting Ralph...".to_string()));
         }
     }
 
-    /// Add a log entry
+    /// Replace the local runner with a read-only follower of another
+    /// instance's run, reached over its `ralphd` websocket RPC bus at
+    /// `addr` (a `host:port`). `process_messages` reconstructs
+    /// `agent_states`, `tasks`, `agent_graph`, and `metrics` purely from
+    /// the replayed envelope stream, exactly as it would for a locally
+    /// spawned subprocess.
+    pub fn connect_to_host(&mut self, addr: String) {
+        self.logs.clear();
+        self.tasks.clear();
+        self.current_thought.clear();
+        self.is_thinking = false;
+        for state in self.agent_states.values_mut() {
+            *state = AgentState::Idle;
+        }
+        self.agent_graph.clear();
+        self.search_index.clear();
+        self.log_base_offset = 0;
+        self.highlighted_log = None;
+        self.metrics = Metrics::default();
+
+        let (log_sender, log_receiver) = unbounded();
+        let (msg_sender, msg_receiver) = unbounded();
+        self.log_receiver = Some(log_receiver);
+        self.msg_receiver = Some(msg_receiver);
+        self.notif_receiver = None;
+        crate::ralph::set_tracing_sender(log_sender.clone());
+
+        match ConnectedSource::connect(addr.clone(), log_sender, msg_sender) {
+            Ok(source) => {
+                self.runner = Some(Box::new(source));
+                self.add_log(LogEntry::system(format!("Following {addr}...")));
+            }
+            Err(e) => {
+                self.add_log(LogEntry::error(format!("Failed to connect to {addr}: {e}")));
+            }
+        }
+    }
+
+    /// Access the active log source, if a run (local or followed) is in
+    /// progress.
+    pub fn runner(&self) -> Option<&dyn LogSource> {
+        self.runner.as_deref()
+    }
+
+    /// Default temperature slider range, sourced from config
+    pub fn temperature_range(&self) -> std::ops::RangeInclusive<f32> {
+        self.config.agents.temperature_range.0..=self.config.agents.temperature_range.1
+    }
+
+    /// Default top-p slider range, sourced from config
+    pub fn top_p_range(&self) -> std::ops::RangeInclusive<f32> {
+        self.config.agents.top_p_range.0..=self.config.agents.top_p_range.1
+    }
+
+    /// Push a notification timestamped with the current animation clock,
+    /// for lifecycle transitions only `process_messages` can see (agent
+    /// start/end, a task completing) rather than raw subprocess text.
+    fn notify(&mut self, severity: Severity, title: impl Into<String>, body: impl Into<String>) {
+        let mut notification = Notification::new(severity, title, body);
+        notification.shown_at = self.animation_time;
+        self.notifications.push(notification);
+    }
+
+    /// Add a log entry. Error/complete toasts are already produced from
+    /// the raw subprocess line by `NotificationBuffer::classify_line` over
+    /// in `notif_receiver` (shared with headless `ralphd` clients), so
+    /// this only needs to index and retain the entry.
     pub fn add_log(&mut self, entry: LogEntry) {
+        let log_id = self.log_base_offset + self.logs.len();
+        self.search_index.index(log_id, &entry.message);
+
         self.logs.push_back(entry);
         if self.logs.len() > MAX_LOG_ENTRIES {
             self.logs.pop_front();
+            self.log_base_offset += 1;
+            self.search_index.prune_before(self.log_base_offset);
         }
     }
 
+    /// Top log matches for `self.search_query`, as `(stable log id,
+    /// score)` pairs, highest first.
+    pub fn search_logs(&self, top_k: usize) -> Vec<(usize, f32)> {
+        self.search_index.search(&self.search_query, top_k)
+    }
+
+    /// Serialize `entries` (whatever the log viewer currently has visible
+    /// after level/search filtering) to a timestamped JSONL file in the
+    /// working directory, one `LogEntry` per line.
+    pub fn export_logs(&self, entries: &[&LogEntry]) -> std::io::Result<PathBuf> {
+        let path = PathBuf::from(format!("ralph-logs-{}.jsonl", Local::now().format("%Y%m%d-%H%M%S")));
+
+        let mut body = String::new();
+        for entry in entries {
+            if let Ok(line) = serde_json::to_string(entry) {
+                body.push_str(&line);
+                body.push('\n');
+            }
+        }
+
+        std::fs::write(&path, body)?;
+        Ok(path)
+    }
+
+    /// Live position in `self.logs` of the entry with stable id `id`, if
+    /// it hasn't been evicted yet.
+    pub fn log_position(&self, id: usize) -> Option<usize> {
+        id.checked_sub(self.log_base_offset).filter(|&pos| pos < self.logs.len())
+    }
+
+    /// Whether the entry at `position` in `self.logs` is the current
+    /// search-result selection.
+    pub fn is_log_highlighted(&self, position: usize) -> bool {
+        self.highlighted_log == Some(self.log_base_offset + position)
+    }
+
     /// Process incoming log messages and update state
     fn process_messages(&mut self) {
+        if let Some(ref r) = self.notif_receiver {
+            let notif_receiver = r.clone();
+            let now = self.animation_time;
+            while let Ok(mut notification) = notif_receiver.try_recv() {
+                notification.shown_at = now;
+                self.notifications.push(notification);
+            }
+        }
+
+        if let Some(ref r) = self.msg_receiver {
+            let msg_receiver = r.clone();
+            let now = self.animation_time;
+            while let Ok(msg) = msg_receiver.try_recv() {
+                let was_active = self.active_agent();
+                self.agent_graph.apply(&msg, now);
+                let is_active = self.agent_graph.active_agent();
+
+                match is_active {
+                    Some(agent) => self.set_agent_active(agent),
+                    None => self.reset_agents_idle(),
+                }
+
+                if was_active != is_active {
+                    if let Some(agent) = is_active {
+                        self.notify(Severity::Info, format!("{} started", agent.name()), "");
+                    }
+                    if let Some(agent) = was_active {
+                        self.notify(Severity::Info, format!("{} finished", agent.name()), "");
+                    }
+                }
+
+                // A `WorkRequest` payload is the prompt the receiving
+                // agent is about to process; meter it as prompt tokens.
+                if msg.msg_type == MessageType::WorkRequest {
+                    if let Some(receiver) = Agent::parse(&msg.receiver) {
+                        let tokens = self.tokenizer.count(&msg.payload.to_string()) as u64;
+                        self.metrics.record_prompt_tokens(receiver, tokens, &self.config.pricing);
+                    }
+                }
+            }
+        }
+
         let receiver = if let Some(ref r) = self.log_receiver {
             r.clone()
         } else {
@@ -165,6 +439,17 @@ impl RalphApp {
         while let Ok(entry) = receiver.try_recv() {
             let message = entry.message.clone();
 
+            // Meter every streamed line as completion tokens for whichever
+            // agent is currently active, so the cost estimate tracks
+            // output as it streams rather than waiting for the subprocess
+            // to self-report `[METRICS]` numbers.
+            if let Some(agent) = self.active_agent() {
+                let tokens = self.tokenizer.count(&message) as u64;
+                if tokens > 0 {
+                    self.metrics.record_completion_tokens(agent, tokens, &self.config.pricing);
+                }
+            }
+
             // Handle thinking blocks
             if message.contains("<think>") {
                 self.is_thinking = true;
@@ -193,8 +478,14 @@ impl RalphApp {
                 continue;
             }
 
-            // Parse agent events from log
-            self.parse_agent_event(&entry);
+            // The typed message bus (handled above) drives agent_states and
+            // agent_graph for normal handoffs; the completion banner is
+            // plain text rather than a `Message`, so it still needs a
+            // dedicated check here.
+            if entry.message.contains("<promise>COMPLETE</promise>") {
+                self.reset_agents_idle();
+                self.agent_graph.clear();
+            }
 
             // Parse metrics
             if entry.message.starts_with("[METRICS]") {
@@ -207,6 +498,7 @@ impl RalphApp {
                 let json_part = entry.message.trim_start_matches("[PLAN]").trim();
                 if let Ok(v) = serde_json::from_str::<Value>(json_part) {
                     if let Some(tasks_val) = v["tasks"].as_array() {
+                        let previous = self.tasks.clone();
                         self.tasks = tasks_val.iter().map(|t| {
                             let id = t["id"].as_u64().unwrap_or(0) as usize;
                             let description = t["description"].as_str().unwrap_or("").to_string();
@@ -218,6 +510,14 @@ impl RalphApp {
                             };
                             Task { id, description, status }
                         }).collect();
+
+                        for task in &self.tasks {
+                            let was_complete = previous.iter()
+                                .any(|p| p.id == task.id && p.status == TaskStatus::Complete);
+                            if task.status == TaskStatus::Complete && !was_complete {
+                                self.notify(Severity::Info, "Task complete", task.description.clone());
+                            }
+                        }
                     }
                 }
             }
@@ -226,77 +526,36 @@ impl RalphApp {
         }
     }
 
-    /// Parse agent state changes from log entries
-    fn parse_agent_event(&mut self, entry: &LogEntry) {
-        let text = &entry.message;
-
-        // Check for agent markers [AGENT:NAME:START/END]
-        if text.contains("[AGENT:") {
-            if text.contains(":START]") {
-                if text.contains("ORCHESTRATOR") {
-                    self.set_agent_active(Agent::Orchestrator);
-                    self.active_connection = Some((Agent::Translator, Agent::Orchestrator));
-                } else if text.contains("ENGINEER") {
-                    self.set_agent_active(Agent::Engineer);
-                    self.active_connection = Some((Agent::Orchestrator, Agent::Engineer));
-                } else if text.contains("DESIGNER") {
-                    self.set_agent_active(Agent::Designer);
-                    self.active_connection = Some((Agent::Engineer, Agent::Designer));
-                } else if text.contains("TRANSLATOR") {
-                    self.set_agent_active(Agent::Translator);
-                    self.active_connection = None;
-                }
-            } else if text.contains(":END]") {
-                // Reset agent to idle (the next START will activate another)
-                if text.contains("ORCHESTRATOR") {
-                    self.agent_states.insert(Agent::Orchestrator, AgentState::Idle);
-                } else if text.contains("ENGINEER") {
-                    self.agent_states.insert(Agent::Engineer, AgentState::Idle);
-                } else if text.contains("DESIGNER") {
-                    self.agent_states.insert(Agent::Designer, AgentState::Idle);
-                } else if text.contains("TRANSLATOR") {
-                    self.agent_states.insert(Agent::Translator, AgentState::Idle);
-                }
-            }
-        }
-
-        // Also check for legacy patterns
-        if text.contains("[Swarm] Orchestrator is thinking") {
-            self.set_agent_active(Agent::Orchestrator);
-            self.active_connection = Some((Agent::Translator, Agent::Orchestrator));
-        } else if text.contains("[Swarm] Engineer is coding") {
-            self.set_agent_active(Agent::Engineer);
-            self.active_connection = Some((Agent::Orchestrator, Agent::Engineer));
-        } else if text.contains("[Swarm] Designer is reviewing") {
-            self.set_agent_active(Agent::Designer);
-            self.active_connection = Some((Agent::Engineer, Agent::Designer));
-        } else if text.contains("[V2] Translator processing") {
-            self.set_agent_active(Agent::Translator);
-        } else if text.contains("[V2] Spawning ASIC") || text.contains("ASIC:") {
-            self.set_agent_active(Agent::Asic);
-            self.active_connection = Some((Agent::Designer, Agent::Asic));
-        } else if text.contains("<promise>COMPLETE</promise>") {
-            // All done - reset all agents
-            for state in self.agent_states.values_mut() {
-                *state = AgentState::Idle;
-            }
-            self.active_connection = None;
-        }
+    fn set_agent_active(&mut self, agent: Agent) {
+        self.reset_agents_idle();
+        self.agent_states.insert(agent, AgentState::Active);
     }
 
-    fn set_agent_active(&mut self, agent: Agent) {
-        // Set all to idle first
+    fn reset_agents_idle(&mut self) {
         for state in self.agent_states.values_mut() {
             *state = AgentState::Idle;
         }
-        // Activate the specified agent
-        self.agent_states.insert(agent, AgentState::Active);
+    }
+
+    /// The agent currently marked `Active`, if any.
+    fn active_agent(&self) -> Option<Agent> {
+        Agent::all()
+            .iter()
+            .copied()
+            .find(|agent| self.agent_states.get(agent) == Some(&AgentState::Active))
     }
 
     /// Check if Ralph is currently running
     pub fn is_running(&self) -> bool {
         self.runner.as_ref().map(|r| r.is_running()).unwrap_or(false)
     }
+
+    /// Check if Ralph is paused. While a run is active this reflects the
+    /// subprocess's acknowledged state (see `RalphRunner::is_paused`)
+    /// rather than the optimistic `is_paused` field.
+    pub fn is_paused(&self) -> bool {
+        self.runner.as_ref().map(|r| r.is_paused()).unwrap_or(self.is_paused)
+    }
 }
 
 impl eframe::App for RalphApp {
@@ -323,6 +582,18 @@ impl eframe::App for RalphApp {
                     } else {
                         ui.label(egui::RichText::new("○ IDLE").color(theme::TEXT_MUTED));
                     }
+
+                    ui.separator();
+
+                    let unread = self.notifications.unread_count();
+                    let bell_label = if unread > 0 {
+                        format!("🔔 {unread}")
+                    } else {
+                        "🔔".to_string()
+                    };
+                    if ui.button(bell_label).clicked() {
+                        self.show_notification_center = !self.show_notification_center;
+                    }
                 });
             });
         });
@@ -335,6 +606,8 @@ impl eframe::App for RalphApp {
                 ui::metrics::show(ui, &self.metrics);
                 ui.add_space(16.0);
                 ui::tasks::show(ui, &self.tasks);
+                ui.add_space(16.0);
+                ui::search::show(ui, self);
             });
 
         // Bottom panel with input
@@ -377,7 +650,7 @@ impl eframe::App for RalphApp {
                         egui::ScrollArea::vertical()
                             .max_height(150.0)
                             .show(ui, |ui| {
-                                ui.label(egui::RichText::new(&self.current_thought).color(theme::TEXT_SECONDARY).small().italics());
+                                ui::markdown::show(ui, &self.current_thought, theme::TEXT_SECONDARY, true);
                             });
                     });
                 ui.add_space(12.0);
@@ -392,5 +665,9 @@ impl eframe::App for RalphApp {
                     ui::logs::show(ui, self);
                 });
         });
+
+        // Toast notifications overlay
+        ui::notifications::show(ctx, self);
+        ui::notifications::show_history(ctx, self);
     }
 }
@@ -0,0 +1,132 @@
+//! Headless Ralph daemon
+//!
+//! Spawns `ralph_loop.sh` exactly like the egui app does, but owns the
+//! `crossbeam` log/message bus on its own and exposes it over a
+//! websocket RPC bus (see `ralph_gui::rpc`) instead of keeping it
+//! in-process. Any number of clients (the egui `RalphApp`, the WASM
+//! `App`, a CLI, ...) can attach concurrently to watch one agent swarm,
+//! and a late joiner is caught up via the replay buffer before it starts
+//! receiving live frames. Clients are read-only: apart from a close,
+//! anything a client sends is ignored.
+
+use crossbeam_channel::unbounded;
+use futures_util::{SinkExt, StreamExt};
+use ralph_gui::config::CompleteConfig;
+use ralph_gui::ralph::RalphRunner;
+use ralph_gui::rpc::{ReplayBuffer, RpcFrame};
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+/// Capacity of the broadcast fan-out; slow clients drop the oldest frame
+/// rather than stalling the whole bus.
+const BROADCAST_CAPACITY: usize = 1024;
+
+#[tokio::main]
+async fn main() {
+    let objective = std::env::args().nth(1).unwrap_or_else(|| "idle".to_string());
+    let config = CompleteConfig::load(None);
+    let bind_addr = std::env::var("RALPHD_BIND").unwrap_or_else(|_| "127.0.0.1:9871".to_string());
+    let sandbox_enabled = std::env::var("RALPHD_SANDBOX").is_ok_and(|v| v == "1" || v == "true");
+
+    ralph_gui::ralph::install_tracing();
+
+    let (log_sender, log_receiver) = unbounded();
+    let (msg_sender, msg_receiver) = unbounded();
+    let (notif_sender, notif_receiver) = unbounded();
+
+    ralph_gui::ralph::set_tracing_sender(log_sender.clone());
+
+    let runner = RalphRunner::new(objective, config, sandbox_enabled, log_sender, msg_sender, notif_sender);
+    if let Err(e) = runner.start() {
+        eprintln!("[ralphd] failed to start ralph_loop.sh: {e}");
+        std::process::exit(1);
+    }
+    // Keep the runner alive for the lifetime of the daemon.
+    let _runner = runner;
+
+    let replay = Arc::new(Mutex::new(ReplayBuffer::default()));
+    let (broadcast_tx, _) = broadcast::channel::<RpcFrame>(BROADCAST_CAPACITY);
+
+    spawn_bridge_thread(log_receiver, replay.clone(), broadcast_tx.clone(), |entry| RpcFrame::Log(entry));
+    spawn_bridge_thread(msg_receiver, replay.clone(), broadcast_tx.clone(), RpcFrame::Message);
+    // Lifecycle notifications ride the same bus as plain log lines so
+    // thin clients don't need a second subscription.
+    spawn_bridge_thread(notif_receiver, replay.clone(), broadcast_tx.clone(), |n| {
+        RpcFrame::Log(ralph_gui::ralph::LogEntry::system(format!("{}: {}", n.title, n.body)))
+    });
+
+    let listener = TcpListener::bind(&bind_addr).await.expect("failed to bind RPC listener");
+    println!("[ralphd] listening on ws://{bind_addr}");
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(pair) => pair,
+            Err(e) => {
+                eprintln!("[ralphd] accept failed: {e}");
+                continue;
+            }
+        };
+
+        let replay = replay.clone();
+        let mut rx = broadcast_tx.subscribe();
+
+        tokio::spawn(async move {
+            let ws = match tokio_tungstenite::accept_async(stream).await {
+                Ok(ws) => ws,
+                Err(e) => {
+                    eprintln!("[ralphd] handshake with {peer} failed: {e}");
+                    return;
+                }
+            };
+            let (mut write, mut read) = ws.split();
+
+            // Catch the new client up before it sees any live frames.
+            let snapshot = replay.lock().unwrap().snapshot();
+            if let Ok(json) = snapshot.encode() {
+                let _ = write.send(WsMessage::Text(json.into())).await;
+            }
+
+            loop {
+                tokio::select! {
+                    frame = rx.recv() => {
+                        let Ok(frame) = frame else { break };
+                        let Ok(json) = frame.encode() else { continue };
+                        if write.send(WsMessage::Text(json.into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    incoming = read.next() => {
+                        match incoming {
+                            Some(Ok(WsMessage::Close(_))) | None => break,
+                            // Clients are read-only; any other message is
+                            // ignored.
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            println!("[ralphd] client {peer} disconnected");
+        });
+    }
+}
+
+/// Bridge a synchronous `crossbeam` receiver into the async broadcast bus,
+/// recording every frame in the replay buffer as it goes out.
+fn spawn_bridge_thread<T: Send + 'static>(
+    receiver: crossbeam_channel::Receiver<T>,
+    replay: Arc<Mutex<ReplayBuffer>>,
+    broadcast_tx: broadcast::Sender<RpcFrame>,
+    to_frame: impl Fn(T) -> RpcFrame + Send + 'static,
+) {
+    std::thread::spawn(move || {
+        for item in receiver.iter() {
+            let frame = to_frame(item);
+            replay.lock().unwrap().push(frame.clone());
+            // No subscribers yet is not an error; the frame simply isn't
+            // replayed live (it's still in the replay buffer).
+            let _ = broadcast_tx.send(frame);
+        }
+    });
+}
@@ -0,0 +1,210 @@
+//! Layered configuration for the Ralph dashboard
+//!
+//! Settings are resolved in priority order: `--config <path>` CLI flag,
+//! then `$XDG_CONFIG_HOME/ralph/config.toml` (falling back to
+//! `~/.config/ralph/config.toml`), then built-in defaults. Individual
+//! fields can be overridden with `RALPH_*` environment variables without
+//! editing the file on disk.
+
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Top-level configuration, mirroring the sections in `config.toml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CompleteConfig {
+    pub runner: RunnerConfig,
+    pub model: ModelConfig,
+    pub server: ServerConfig,
+    pub agents: AgentsConfig,
+    pub sandbox: SandboxConfig,
+    pub pricing: PricingConfig,
+}
+
+impl Default for CompleteConfig {
+    fn default() -> Self {
+        Self {
+            runner: RunnerConfig::default(),
+            model: ModelConfig::default(),
+            server: ServerConfig::default(),
+            agents: AgentsConfig::default(),
+            sandbox: SandboxConfig::default(),
+            pricing: PricingConfig::default(),
+        }
+    }
+}
+
+/// Controls how the `ralph_loop.sh` subprocess is launched.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RunnerConfig {
+    /// Path to `ralph_loop.sh`. Relative paths are resolved against
+    /// `working_dir`.
+    pub script_path: PathBuf,
+    /// Working directory the script is spawned in.
+    pub working_dir: PathBuf,
+    /// Extra flags always appended to the invocation (e.g. `--v2`).
+    pub forced_flags: Vec<String>,
+}
+
+impl Default for RunnerConfig {
+    fn default() -> Self {
+        Self {
+            script_path: PathBuf::from("ralph_loop.sh"),
+            working_dir: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+            forced_flags: vec!["--v2".to_string()],
+        }
+    }
+}
+
+/// Default model selection and sampling parameters.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ModelConfig {
+    pub name: String,
+    pub temperature: f32,
+    pub top_p: f32,
+}
+
+impl Default for ModelConfig {
+    fn default() -> Self {
+        Self {
+            name: "qwen3:8b".to_string(),
+            temperature: 0.7,
+            top_p: 0.9,
+        }
+    }
+}
+
+/// Backend HTTP server the dashboard talks to.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub base_url: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "http://127.0.0.1:3000".to_string(),
+        }
+    }
+}
+
+/// Default slider ranges for the per-agent Control Center sliders.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AgentsConfig {
+    pub temperature_range: (f32, f32),
+    pub top_p_range: (f32, f32),
+}
+
+impl Default for AgentsConfig {
+    fn default() -> Self {
+        Self {
+            temperature_range: (0.0, 2.0),
+            top_p_range: (0.0, 1.0),
+        }
+    }
+}
+
+/// Controls the Docker container used when Sandbox Mode is enabled.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SandboxConfig {
+    /// Image the `ralph_loop.sh` run is executed inside.
+    pub image: String,
+    /// Extra flags inserted into `docker run` before the image name,
+    /// e.g. `["--network", "none"]` to lock a run down further.
+    pub extra_args: Vec<String>,
+}
+
+impl Default for SandboxConfig {
+    fn default() -> Self {
+        Self {
+            image: "ralph-sandbox:latest".to_string(),
+            extra_args: Vec::new(),
+        }
+    }
+}
+
+/// Per-1K-token price table used to turn the metered token counts in
+/// `Metrics` into an estimated running cost.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PricingConfig {
+    /// USD per 1,000 prompt tokens.
+    pub prompt_per_1k: f64,
+    /// USD per 1,000 completion tokens.
+    pub completion_per_1k: f64,
+}
+
+impl Default for PricingConfig {
+    fn default() -> Self {
+        Self {
+            prompt_per_1k: 0.0005,
+            completion_per_1k: 0.0015,
+        }
+    }
+}
+
+impl CompleteConfig {
+    /// Resolve configuration from the `--config` flag, the XDG config
+    /// directory, environment overrides, and defaults, in that order.
+    pub fn load(cli_path: Option<PathBuf>) -> Self {
+        let path = cli_path.or_else(Self::xdg_config_path);
+
+        let mut config = match path.as_ref().and_then(|p| std::fs::read_to_string(p).ok()) {
+            Some(raw) => toml::from_str(&raw).unwrap_or_default(),
+            None => CompleteConfig::default(),
+        };
+
+        config.apply_env_overrides();
+        config
+    }
+
+    fn xdg_config_path() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+        Some(base.join("ralph").join("config.toml"))
+    }
+
+    fn apply_env_overrides(&mut self) {
+        if let Ok(v) = std::env::var("RALPH_SCRIPT_PATH") {
+            self.runner.script_path = PathBuf::from(v);
+        }
+        if let Ok(v) = std::env::var("RALPH_WORKING_DIR") {
+            self.runner.working_dir = PathBuf::from(v);
+        }
+        if let Ok(v) = std::env::var("RALPH_MODEL") {
+            self.model.name = v;
+        }
+        if let Ok(v) = std::env::var("RALPH_SERVER_URL") {
+            self.server.base_url = v;
+        }
+        if let Ok(v) = std::env::var("RALPH_SANDBOX_IMAGE") {
+            self.sandbox.image = v;
+        }
+        if let Ok(v) = std::env::var("RALPH_PRICE_PROMPT_PER_1K") {
+            if let Ok(price) = v.parse() {
+                self.pricing.prompt_per_1k = price;
+            }
+        }
+        if let Ok(v) = std::env::var("RALPH_PRICE_COMPLETION_PER_1K") {
+            if let Ok(price) = v.parse() {
+                self.pricing.completion_per_1k = price;
+            }
+        }
+    }
+
+    /// Full path to the runner script, resolved against `working_dir`
+    /// when the configured path is relative.
+    pub fn resolved_script_path(&self) -> PathBuf {
+        if self.runner.script_path.is_absolute() {
+            self.runner.script_path.clone()
+        } else {
+            self.runner.working_dir.join(&self.runner.script_path)
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Typed agent message bus -> force-graph edge state
+//!
+//! `ui::agent_flow` used to infer which agents were talking by sniffing
+//! substrings like `[AGENT:ENGINEER:START]` out of raw stdout. The subprocess
+//! already emits a typed `Message` for every handoff (see
+//! `ralph::messages`), so this folds that bus into graph state instead:
+//! a real `(sender, receiver)` pair becomes an edge, and `Complete`/`Abort`
+//! clears the active agent. Existing edges are kept and drop out of
+//! `recent_edges` once they age past its window.
+
+use std::collections::HashMap;
+
+use crate::app::Agent;
+use crate::ralph::{Message, MessageType};
+
+/// State of one directed edge between two agents.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EdgeState {
+    /// `RalphApp::animation_time` the edge last carried a message at.
+    pub last_active_at: f32,
+}
+
+/// Live view of who's talking to whom, built from the typed message bus.
+#[derive(Debug, Clone, Default)]
+pub struct AgentGraph {
+    edges: HashMap<(Agent, Agent), EdgeState>,
+    active: Option<Agent>,
+}
+
+impl AgentGraph {
+    /// Fold one bus message into the graph.
+    pub fn apply(&mut self, msg: &Message, now: f32) {
+        if matches!(msg.msg_type, MessageType::Complete | MessageType::Abort) {
+            self.active = None;
+            return;
+        }
+
+        let (Some(from), Some(to)) = (Agent::parse(&msg.sender), Agent::parse(&msg.receiver)) else {
+            return;
+        };
+
+        self.edges.entry((from, to)).or_default().last_active_at = now;
+        self.active = Some(from);
+    }
+
+    /// The agent that most recently sent a message, if the graph hasn't
+    /// since been reset by a `Complete`/`Abort`.
+    pub fn active_agent(&self) -> Option<Agent> {
+        self.active
+    }
+
+    /// Edges that carried a message within `window` seconds of `now`,
+    /// paired with their age.
+    pub fn recent_edges(&self, now: f32, window: f32) -> Vec<(Agent, Agent, f32)> {
+        self.edges
+            .iter()
+            .map(|(&(from, to), state)| (from, to, now - state.last_active_at))
+            .filter(|(_, _, age)| *age >= 0.0 && *age <= window)
+            .collect()
+    }
+
+    /// Drop all edge and activity state, e.g. when a new run starts.
+    pub fn clear(&mut self) {
+        self.edges.clear();
+        self.active = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn message(msg_type: MessageType, sender: &str, receiver: &str) -> Message {
+        Message {
+            id: "1".to_string(),
+            msg_type,
+            sender: sender.to_string(),
+            receiver: receiver.to_string(),
+            payload: json!({}),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            correlation_id: None,
+            metadata: json!({}),
+        }
+    }
+
+    #[test]
+    fn apply_records_edge_and_active_sender() {
+        let mut graph = AgentGraph::default();
+        graph.apply(&message(MessageType::WorkRequest, "orchestrator", "engineer"), 1.0);
+
+        assert_eq!(graph.active_agent(), Some(Agent::Orchestrator));
+        assert_eq!(graph.recent_edges(1.0, 5.0), vec![(Agent::Orchestrator, Agent::Engineer, 0.0)]);
+    }
+
+    #[test]
+    fn unparseable_agent_names_are_ignored() {
+        let mut graph = AgentGraph::default();
+        graph.apply(&message(MessageType::WorkRequest, "gui", "engineer"), 1.0);
+
+        assert_eq!(graph.active_agent(), None);
+        assert!(graph.recent_edges(1.0, 5.0).is_empty());
+    }
+
+    #[test]
+    fn complete_resets_active_agent() {
+        let mut graph = AgentGraph::default();
+        graph.apply(&message(MessageType::WorkRequest, "orchestrator", "engineer"), 1.0);
+        graph.apply(&message(MessageType::Complete, "orchestrator", "gui"), 2.0);
+
+        assert_eq!(graph.active_agent(), None);
+    }
+
+    #[test]
+    fn recent_edges_excludes_stale_activity() {
+        let mut graph = AgentGraph::default();
+        graph.apply(&message(MessageType::WorkRequest, "orchestrator", "engineer"), 1.0);
+
+        assert!(graph.recent_edges(10.0, 5.0).is_empty());
+    }
+}
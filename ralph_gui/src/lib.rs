@@ -0,0 +1,20 @@
+//! Shared types for the Ralph dashboard and its headless daemon
+//!
+//! The egui binary (`main.rs`) and the `ralphd` daemon binary both
+//! depend on this library for the config, runner, and RPC wire format so
+//! they stay in lockstep.
+
+pub mod app;
+pub mod config;
+pub mod graph;
+pub mod markdown;
+pub mod notifications;
+pub mod ralph;
+pub mod rpc;
+pub mod search;
+pub mod theme;
+pub mod tokenizer;
+pub mod ui;
+
+#[cfg(test)]
+mod tests;
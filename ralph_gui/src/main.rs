@@ -1,15 +1,29 @@
 //! Ralph AI - Native GUI Dashboard
 //!
 //! A modern dashboard for visualizing Ralph AI agent activity in real-time.
+//! Shared logic lives in the `ralph_gui` library crate so the headless
+//! `ralphd` daemon binary (see `src/bin/ralphd.rs`) can reuse it.
 
-mod app;
-mod theme;
-mod ui;
-mod ralph;
+use ralph_gui::app::RalphApp;
+use ralph_gui::config::CompleteConfig;
+use ralph_gui::theme;
 
-use app::RalphApp;
+/// Parse `--config <path>` out of the process arguments, if present.
+fn cli_config_path() -> Option<std::path::PathBuf> {
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return args.next().map(std::path::PathBuf::from);
+        }
+    }
+    None
+}
 
 fn main() -> eframe::Result<()> {
+    ralph_gui::ralph::install_tracing();
+
+    let config = CompleteConfig::load(cli_config_path());
+
     // Set up native window options
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -29,7 +43,7 @@ fn main() -> eframe::Result<()> {
             style.visuals = theme::dark_visuals();
             cc.egui_ctx.set_style(style);
 
-            Ok(Box::new(RalphApp::new(cc)))
+            Ok(Box::new(RalphApp::new(cc, config)))
         }),
     )
 }
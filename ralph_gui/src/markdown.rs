@@ -0,0 +1,221 @@
+//! Minimal Markdown parser for agent output and `<think>` blocks
+//!
+//! Headings, bold/italic, inline code, list items, and fenced code
+//! blocks are parsed into a small block/span model that `ui::markdown`
+//! lays out with egui widgets. Deliberately hand-rolled rather than
+//! pulling in a full CommonMark implementation: agent/thought text is a
+//! narrow slice of Markdown, and this mirrors the rest of the crate's
+//! preference for small self-contained parsers (see `tokenizer.rs`,
+//! `search.rs`) over heavyweight dependencies.
+
+/// One run of text within a block, with the inline styling that applied
+/// to it (`**bold**`, `*italic*`, `` `code` ``).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+}
+
+impl Span {
+    fn plain(text: impl Into<String>) -> Self {
+        Self { text: text.into(), bold: false, italic: false, code: false }
+    }
+}
+
+/// A block-level element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading(u8, Vec<Span>),
+    Paragraph(Vec<Span>),
+    ListItem(Vec<Span>),
+    CodeBlock { language: Option<String>, lines: Vec<Vec<CodeToken>> },
+}
+
+/// How one token within a highlighted code block should be colored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Keyword,
+    String,
+    Comment,
+    Number,
+    Plain,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeToken {
+    pub text: String,
+    pub kind: TokenKind,
+}
+
+/// Parse `text` into a sequence of blocks.
+pub fn parse(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = text.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if let Some(rest) = line.trim_start().strip_prefix("```") {
+            let language = if rest.trim().is_empty() { None } else { Some(rest.trim().to_string()) };
+            let mut code_lines = Vec::new();
+            for fence_line in lines.by_ref() {
+                if fence_line.trim_start().starts_with("```") {
+                    break;
+                }
+                code_lines.push(fence_line.to_string());
+            }
+            let highlighted = code_lines.iter().map(|l| highlight_line(l, language.as_deref())).collect();
+            blocks.push(Block::CodeBlock { language, lines: highlighted });
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(heading) = trimmed.strip_prefix("### ") {
+            blocks.push(Block::Heading(3, parse_inline(heading)));
+        } else if let Some(heading) = trimmed.strip_prefix("## ") {
+            blocks.push(Block::Heading(2, parse_inline(heading)));
+        } else if let Some(heading) = trimmed.strip_prefix("# ") {
+            blocks.push(Block::Heading(1, parse_inline(heading)));
+        } else if let Some(item) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+            blocks.push(Block::ListItem(parse_inline(item)));
+        } else if !trimmed.is_empty() {
+            blocks.push(Block::Paragraph(parse_inline(line)));
+        }
+    }
+
+    blocks
+}
+
+/// Parse one line of inline Markdown (`**bold**`, `*italic*`,
+/// `` `code` ``) into styled spans.
+fn parse_inline(line: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let chars: Vec<char> = line.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i..].starts_with(&['*', '*']) {
+            if let Some(end) = find_closing(&chars, i + 2, &['*', '*']) {
+                flush_plain(&mut spans, &mut current);
+                let inner: String = chars[i + 2..end].iter().collect();
+                spans.push(Span { text: inner, bold: true, italic: false, code: false });
+                i = end + 2;
+                continue;
+            }
+        } else if chars[i] == '`' {
+            if let Some(end) = find_closing(&chars, i + 1, &['`']) {
+                flush_plain(&mut spans, &mut current);
+                let inner: String = chars[i + 1..end].iter().collect();
+                spans.push(Span { text: inner, bold: false, italic: false, code: true });
+                i = end + 1;
+                continue;
+            }
+        } else if chars[i] == '*' {
+            if let Some(end) = find_closing(&chars, i + 1, &['*']) {
+                flush_plain(&mut spans, &mut current);
+                let inner: String = chars[i + 1..end].iter().collect();
+                spans.push(Span { text: inner, bold: false, italic: true, code: false });
+                i = end + 1;
+                continue;
+            }
+        }
+
+        current.push(chars[i]);
+        i += 1;
+    }
+
+    flush_plain(&mut spans, &mut current);
+    spans
+}
+
+fn flush_plain(spans: &mut Vec<Span>, current: &mut String) {
+    if !current.is_empty() {
+        spans.push(Span::plain(std::mem::take(current)));
+    }
+}
+
+/// Find the index of the first occurrence of `delim` at or after `from`,
+/// returning `None` (so the opening marker is treated as plain text) if
+/// it never closes.
+fn find_closing(chars: &[char], from: usize, delim: &[char]) -> Option<usize> {
+    let mut i = from;
+    while i + delim.len() <= chars.len() {
+        if chars[i..i + delim.len()] == *delim {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Keyword sets for the regex-free, substring-based highlighter below.
+/// Coarse but cheap, and good enough for short agent-emitted snippets.
+fn keywords_for(language: &str) -> &'static [&'static str] {
+    match language.to_ascii_lowercase().as_str() {
+        "rust" | "rs" => &["fn", "let", "mut", "pub", "struct", "enum", "impl", "trait", "use", "match", "if", "else", "for", "while", "loop", "return", "self", "Self", "const", "async", "await"],
+        "python" | "py" => &["def", "class", "import", "from", "return", "if", "elif", "else", "for", "while", "with", "as", "self", "None", "True", "False", "lambda"],
+        "javascript" | "js" | "typescript" | "ts" => &["function", "const", "let", "var", "return", "if", "else", "for", "while", "class", "import", "export", "async", "await", "new"],
+        "bash" | "sh" => &["if", "then", "else", "fi", "for", "do", "done", "while", "function", "echo", "export", "local"],
+        "json" => &["true", "false", "null"],
+        _ => &[],
+    }
+}
+
+/// Classify one line of code into colored tokens via cheap lexical
+/// rules: `//`/`#` comments, quoted strings, numbers, and a per-language
+/// keyword list. Not a real tokenizer, just enough to make pasted
+/// snippets scannable.
+fn highlight_line(line: &str, language: Option<&str>) -> Vec<CodeToken> {
+    let keywords = language.map(keywords_for).unwrap_or(&[]);
+
+    if let Some(comment_start) = line.find("//").or_else(|| if language == Some("bash") || language == Some("sh") || language == Some("python") || language == Some("py") { line.find('#') } else { None }) {
+        let mut tokens = tokenize_code(&line[..comment_start], keywords);
+        tokens.push(CodeToken { text: line[comment_start..].to_string(), kind: TokenKind::Comment });
+        return tokens;
+    }
+
+    tokenize_code(line, keywords)
+}
+
+fn tokenize_code(segment: &str, keywords: &[&str]) -> Vec<CodeToken> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut chars = segment.chars().peekable();
+
+    let flush_word = |word: &mut String, tokens: &mut Vec<CodeToken>, keywords: &[&str]| {
+        if word.is_empty() {
+            return;
+        }
+        let kind = if keywords.contains(&word.as_str()) {
+            TokenKind::Keyword
+        } else if word.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+            TokenKind::Number
+        } else {
+            TokenKind::Plain
+        };
+        tokens.push(CodeToken { text: std::mem::take(word), kind });
+    };
+
+    while let Some(c) = chars.next() {
+        if c == '"' || c == '\'' {
+            flush_word(&mut word, &mut tokens, keywords);
+            let mut string_text = String::from(c);
+            for s in chars.by_ref() {
+                string_text.push(s);
+                if s == c {
+                    break;
+                }
+            }
+            tokens.push(CodeToken { text: string_text, kind: TokenKind::String });
+        } else if c.is_whitespace() || "(){}[]<>,;:.".contains(c) {
+            flush_word(&mut word, &mut tokens, keywords);
+            tokens.push(CodeToken { text: c.to_string(), kind: TokenKind::Plain });
+        } else {
+            word.push(c);
+        }
+    }
+    flush_word(&mut word, &mut tokens, keywords);
+
+    tokens
+}
@@ -0,0 +1,111 @@
+//! Toast notifications for important agent lifecycle transitions
+//!
+//! Complements the scrolling `LogEntry` stream with a bounded buffer of
+//! attention-worthy events (run finished, agent errored, sandbox failed
+//! to start) that the UI can surface as dismissible toasts.
+
+use chrono::Local;
+use std::collections::VecDeque;
+
+/// Maximum number of notifications retained in the ring buffer
+const MAX_NOTIFICATIONS: usize = 50;
+
+/// How urgently a notification should be presented
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A single notification surfaced to the user
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub id: u64,
+    pub severity: Severity,
+    pub title: String,
+    pub body: String,
+    pub timestamp: String,
+    pub agent: Option<String>,
+    /// Pinned notifications are not auto-dismissed
+    pub pinned: bool,
+    /// Monotonic seconds (app animation clock) this notification was shown at
+    pub shown_at: f32,
+    /// Set once the notification history panel has been opened since it
+    /// arrived; drives the header bell's unread badge.
+    pub read: bool,
+}
+
+impl Notification {
+    pub fn new(severity: Severity, title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            id: fastrand::u64(..),
+            severity,
+            title: title.into(),
+            body: body.into(),
+            timestamp: Local::now().format("%H:%M:%S").to_string(),
+            agent: None,
+            pinned: false,
+            shown_at: 0.0,
+            read: false,
+        }
+    }
+
+    pub fn with_agent(mut self, agent: impl Into<String>) -> Self {
+        self.agent = Some(agent.into());
+        self
+    }
+}
+
+/// Bounded ring buffer of recent notifications
+#[derive(Debug, Default)]
+pub struct NotificationBuffer {
+    entries: VecDeque<Notification>,
+}
+
+impl NotificationBuffer {
+    pub fn push(&mut self, notification: Notification) {
+        self.entries.push_back(notification);
+        if self.entries.len() > MAX_NOTIFICATIONS {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Notification> {
+        self.entries.iter()
+    }
+
+    pub fn dismiss(&mut self, id: u64) {
+        self.entries.retain(|n| n.id != id);
+    }
+
+    pub fn pin(&mut self, id: u64, pinned: bool) {
+        if let Some(n) = self.entries.iter_mut().find(|n| n.id == id) {
+            n.pinned = pinned;
+        }
+    }
+
+    /// Number of notifications not yet seen in the history panel.
+    pub fn unread_count(&self) -> usize {
+        self.entries.iter().filter(|n| !n.read).count()
+    }
+
+    /// Mark every notification as seen, e.g. when the history panel opens.
+    pub fn mark_all_read(&mut self) {
+        for n in self.entries.iter_mut() {
+            n.read = true;
+        }
+    }
+
+    /// Recognize a subprocess log line as a lifecycle event worth a toast,
+    /// if any.
+    pub fn classify_line(line: &str) -> Option<Notification> {
+        if line.contains("[ERROR]") || line.contains("ERROR") {
+            Some(Notification::new(Severity::Error, "Agent error", line.to_string()))
+        } else if line.contains("<promise>COMPLETE</promise>") {
+            Some(Notification::new(Severity::Info, "Run complete", "The swarm finished its objective."))
+        } else {
+            None
+        }
+    }
+}
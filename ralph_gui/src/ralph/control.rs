@@ -0,0 +1,27 @@
+//! GUI -> subprocess control protocol
+//!
+//! Mirrors the `[MESSAGE] {json}` convention already used for the agent
+//! message bus (see [`super::messages`]), but carries commands from the GUI
+//! down to `ralph_loop.sh` rather than agent-to-agent chatter. Commands are
+//! written to the child's stdin as `[CONTROL] {json}`; the subprocess is
+//! expected to echo an acknowledgement back on stdout as
+//! `[CONTROL_ACK] {json}` once it has actually applied the command, so the
+//! runner can track real state instead of assuming the write succeeded.
+
+use serde::{Deserialize, Serialize};
+
+/// A command sent from the GUI to the running subprocess.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlCommand {
+    /// Pause the agent loop after the current step.
+    Pause,
+    /// Resume a paused agent loop.
+    Resume,
+    /// Drop any in-flight/queued work on the diagnostic bus.
+    Flush,
+    /// Enable or disable a single agent.
+    SetAgentEnabled { agent: String, enabled: bool },
+    /// Update sampling parameters for a single agent.
+    SetParams { agent: String, temperature: f32, top_p: f32 },
+}
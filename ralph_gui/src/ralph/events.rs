@@ -1,10 +1,15 @@
 //! Event types and log parsing
 
 use chrono::Local;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::app::Agent;
+use crate::config::PricingConfig;
 
 /// Log entry level
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LogLevel {
     Info,
     System,
@@ -15,7 +20,7 @@ pub enum LogLevel {
 }
 
 /// A single log entry
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
     pub timestamp: String,
     pub level: LogLevel,
@@ -82,13 +87,28 @@ impl LogEntry {
 }
 
 /// Agent state
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum AgentState {
     #[default]
     Idle,
     Active,
 }
 
+/// Metered prompt/completion token counts for one agent, plus the
+/// estimated cost they've incurred so far.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AgentTokenUsage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub cost_usd: f64,
+}
+
+impl AgentTokenUsage {
+    pub fn total_tokens(&self) -> u64 {
+        self.prompt_tokens + self.completion_tokens
+    }
+}
+
 /// Metrics from Ralph execution
 #[derive(Debug, Clone, Default)]
 pub struct Metrics {
@@ -96,6 +116,12 @@ pub struct Metrics {
     pub last_duration_ms: u64,
     pub active_model: String,
     pub iterations: u32,
+    /// Client-side token/cost metering, keyed by agent. Populated by the
+    /// BPE-estimated token counts in `RalphApp::process_messages`, which
+    /// is separate accounting from `total_tokens` above (the subprocess's
+    /// own self-reported `[METRICS]` numbers).
+    pub per_agent_tokens: HashMap<Agent, AgentTokenUsage>,
+    pub estimated_cost_usd: f64,
 }
 
 impl Metrics {
@@ -106,9 +132,33 @@ impl Metrics {
             last_duration_ms: 0,
             active_model: String::from("qwen2.5-coder:14b"),
             iterations: 0,
+            per_agent_tokens: HashMap::new(),
+            estimated_cost_usd: 0.0,
         }
     }
 
+    /// Attribute `tokens` prompt tokens to `agent` (e.g. a `WorkRequest`
+    /// payload it was just handed) and fold the priced cost into both the
+    /// per-agent and running totals.
+    pub fn record_prompt_tokens(&mut self, agent: Agent, tokens: u64, pricing: &PricingConfig) {
+        let cost = tokens as f64 / 1000.0 * pricing.prompt_per_1k;
+        let usage = self.per_agent_tokens.entry(agent).or_default();
+        usage.prompt_tokens += tokens;
+        usage.cost_usd += cost;
+        self.estimated_cost_usd += cost;
+    }
+
+    /// Attribute `tokens` completion tokens to `agent` (streamed log or
+    /// thought text produced while it was active) and fold the priced
+    /// cost into both the per-agent and running totals.
+    pub fn record_completion_tokens(&mut self, agent: Agent, tokens: u64, pricing: &PricingConfig) {
+        let cost = tokens as f64 / 1000.0 * pricing.completion_per_1k;
+        let usage = self.per_agent_tokens.entry(agent).or_default();
+        usage.completion_tokens += tokens;
+        usage.cost_usd += cost;
+        self.estimated_cost_usd += cost;
+    }
+
     pub fn update_from_json(&mut self, json_str: &str) {
         if let Ok(v) = serde_json::from_str::<Value>(json_str) {
             if let Some(type_str) = v["type"].as_str() {
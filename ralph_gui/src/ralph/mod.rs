@@ -4,7 +4,14 @@
 
 mod runner;
 mod events;
+pub mod control;
 pub mod messages;
+mod source;
+mod tracing_bridge;
 
-pub use runner::RalphRunner;
-pub use events::{LogEntry, LogLevel, AgentState, Metrics};
+pub use runner::{RalphRunner, RunnerEvent, classify_line};
+pub use events::{LogEntry, LogLevel, AgentState, Metrics, AgentTokenUsage};
+pub use control::ControlCommand;
+pub use messages::{Message, MessageType};
+pub use source::{LogSource, ConnectedSource};
+pub use tracing_bridge::{install as install_tracing, set_sender as set_tracing_sender, THOUGHT_TARGET};
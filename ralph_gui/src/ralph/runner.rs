@@ -8,55 +8,108 @@ use std::sync::{Arc, Mutex};
 use std::thread;
 use crossbeam_channel::Sender;
 
+use crate::config::CompleteConfig;
+use crate::notifications::{Notification, NotificationBuffer};
+use super::control::ControlCommand;
 use super::events::LogEntry;
 use super::messages::Message;
 
 /// Manages the Ralph subprocess
 pub struct RalphRunner {
     objective: String,
+    config: CompleteConfig,
+    sandbox_enabled: bool,
     log_sender: Sender<LogEntry>,
     msg_sender: Sender<Message>,
+    notif_sender: Sender<Notification>,
     child: Arc<Mutex<Option<Child>>>,
     running: Arc<Mutex<bool>>,
+    /// Acknowledged pause state, updated only once the subprocess echoes a
+    /// `[CONTROL_ACK]` line back, never by the GUI optimistically.
+    paused: Arc<Mutex<bool>>,
     stdin: Arc<Mutex<Option<std::process::ChildStdin>>>,
 }
 
 impl RalphRunner {
-    pub fn new(objective: String, log_sender: Sender<LogEntry>, msg_sender: Sender<Message>) -> Self {
+    pub fn new(
+        objective: String,
+        config: CompleteConfig,
+        sandbox_enabled: bool,
+        log_sender: Sender<LogEntry>,
+        msg_sender: Sender<Message>,
+        notif_sender: Sender<Notification>,
+    ) -> Self {
         Self {
             objective,
+            config,
+            sandbox_enabled,
             log_sender,
             msg_sender,
+            notif_sender,
             child: Arc::new(Mutex::new(None)),
             running: Arc::new(Mutex::new(false)),
+            paused: Arc::new(Mutex::new(false)),
             stdin: Arc::new(Mutex::new(None)),
         }
     }
 
-    /// Start the Ralph process
-    pub fn start(&self) -> Result<(), String> {
-        // Get the project directory (parent of ralph_gui)
-        let project_dir = std::env::current_dir()
-            .map_err(|e| e.to_string())?
-            .parent()
-            .map(|p| p.to_path_buf())
-            .unwrap_or_else(|| std::env::current_dir().unwrap());
-
-        let script_path = project_dir.join("ralph_loop.sh");
+    /// Build the `bash`/`docker` invocation for this run, without spawning
+    /// it. Split out of [`Self::start`] so the sandbox-vs-host decision can
+    /// be exercised without running `ralph_loop.sh` or a Docker daemon;
+    /// the script file itself must still exist at the resolved path.
+    fn build_command(&self) -> Result<Command, String> {
+        let working_dir = &self.config.runner.working_dir;
+        let script_path = self.config.resolved_script_path();
 
         if !script_path.exists() {
-            // Try alternate location
-            let alt_path = std::path::Path::new("/home/tristan/Documents/Ralph Ai/ai_tech_stack/ralph_loop.sh");
-            if !alt_path.exists() {
-                return Err(format!("ralph_loop.sh not found at {:?}", script_path));
+            return Err(format!("ralph_loop.sh not found at {:?}", script_path));
+        }
+
+        if self.sandbox_enabled {
+            // Sandbox Mode only supports a `script_path` inside
+            // `working_dir`, since that's what gets bind-mounted into the
+            // container; an absolute path elsewhere on the host isn't
+            // visible from in there.
+            let relative_script = script_path.strip_prefix(working_dir).map_err(|_| {
+                format!(
+                    "Sandbox Mode requires script_path ({:?}) to live under working_dir ({:?})",
+                    script_path, working_dir
+                )
+            })?;
+
+            let mut command = Command::new("docker");
+            command
+                .arg("run")
+                .arg("--rm")
+                .arg("-i")
+                .arg("-v")
+                .arg(format!("{}:/workspace", working_dir.display()))
+                .arg("-w")
+                .arg("/workspace");
+            for arg in &self.config.sandbox.extra_args {
+                command.arg(arg);
+            }
+            command.arg(&self.config.sandbox.image).arg("bash").arg(relative_script);
+            for flag in &self.config.runner.forced_flags {
+                command.arg(flag);
             }
+            command.arg(&self.objective);
+            Ok(command)
+        } else {
+            let mut command = Command::new("bash");
+            command.arg(&script_path);
+            for flag in &self.config.runner.forced_flags {
+                command.arg(flag);
+            }
+            command.arg(&self.objective).current_dir(working_dir);
+            Ok(command)
         }
+    }
 
-        let mut child = Command::new("bash")
-            .arg(&script_path)
-            .arg("--v2")  // Force V2 mode for the GUI
-            .arg(&self.objective)
-            .current_dir(&project_dir)
+    /// Start the Ralph process
+    pub fn start(&self) -> Result<(), String> {
+        let mut command = self.build_command()?;
+        let mut child = command
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .stdin(Stdio::piped())
@@ -76,20 +129,43 @@ impl RalphRunner {
         // Spawn thread to read stdout
         let log_sender = self.log_sender.clone();
         let msg_sender = self.msg_sender.clone();
+        let notif_sender = self.notif_sender.clone();
         let running_stdout = self.running.clone();
+        let paused_stdout = self.paused.clone();
         thread::spawn(move || {
             let reader = BufReader::new(stdout);
             for line in reader.lines().flatten() {
-                if !line.trim().is_empty() {
-                    if line.starts_with("[MESSAGE]") {
-                        let json_part = line.trim_start_matches("[MESSAGE]").trim();
-                        if let Ok(msg) = serde_json::from_str::<Message>(json_part) {
-                            let _ = msg_sender.send(msg);
+                if let Some(notification) = NotificationBuffer::classify_line(&line) {
+                    let _ = notif_sender.send(notification);
+                }
+
+                match classify_line(&line) {
+                    RunnerEvent::ParsedMessage(msg) => {
+                        let _ = msg_sender.send(msg);
+                        let _ = log_sender.send(LogEntry::parse(&line));
+                    }
+                    RunnerEvent::MalformedMessage => {
+                        let _ = log_sender.send(LogEntry::parse(&line));
+                    }
+                    RunnerEvent::ControlAck(ack) => {
+                        match ack {
+                            ControlCommand::Pause => *paused_stdout.lock().unwrap() = true,
+                            ControlCommand::Resume => *paused_stdout.lock().unwrap() = false,
+                            ControlCommand::Flush => {
+                                let _ = log_sender.send(LogEntry::system("Emergency bus flush acknowledged".to_string()));
+                            }
+                            ControlCommand::SetAgentEnabled { agent, enabled } => {
+                                let _ = log_sender.send(LogEntry::system(format!("{agent} enabled set to {enabled} (acked)")));
+                            }
+                            ControlCommand::SetParams { agent, temperature, top_p } => {
+                                let _ = log_sender.send(LogEntry::system(format!("{agent} params updated (acked): temp={temperature:.2} top_p={top_p:.2}")));
+                            }
                         }
                     }
-                    
-                    let entry = LogEntry::parse(&line);
-                    let _ = log_sender.send(entry);
+                    RunnerEvent::Log(entry) => {
+                        let _ = log_sender.send(entry);
+                    }
+                    RunnerEvent::Empty => {}
                 }
             }
             *running_stdout.lock().unwrap() = false;
@@ -97,10 +173,14 @@ impl RalphRunner {
 
         // Spawn thread to read stderr
         let log_sender_err = self.log_sender.clone();
+        let notif_sender_err = self.notif_sender.clone();
         thread::spawn(move || {
             let reader = BufReader::new(stderr);
             for line in reader.lines().flatten() {
                 if !line.trim().is_empty() {
+                    if let Some(notification) = NotificationBuffer::classify_line(&line) {
+                        let _ = notif_sender_err.send(notification);
+                    }
                     let entry = LogEntry::error(line);
                     let _ = log_sender_err.send(entry);
                 }
@@ -127,6 +207,26 @@ impl RalphRunner {
         }
     }
 
+    /// Send a control command (pause/resume/flush/agent settings) to the
+    /// Ralph process. The command takes effect once the subprocess echoes
+    /// back a matching `[CONTROL_ACK]` line; see [`Self::is_paused`].
+    pub fn send_control(&self, cmd: &ControlCommand) -> Result<(), String> {
+        if let Some(ref mut stdin) = *self.stdin.lock().unwrap() {
+            let json = serde_json::to_string(cmd).map_err(|e| e.to_string())?;
+            writeln!(stdin, "[CONTROL] {}", json).map_err(|e| e.to_string())?;
+            stdin.flush().map_err(|e| e.to_string())?;
+            Ok(())
+        } else {
+            Err("Stdin not available".to_string())
+        }
+    }
+
+    /// Whether the subprocess has acknowledged a pause. Reflects real
+    /// subprocess state, not the GUI's optimistic request.
+    pub fn is_paused(&self) -> bool {
+        *self.paused.lock().unwrap()
+    }
+
     /// Kill the Ralph process
     pub fn kill(&self) {
         if let Some(ref mut child) = *self.child.lock().unwrap() {
@@ -141,3 +241,51 @@ impl Drop for RalphRunner {
         self.kill();
     }
 }
+
+/// Result of classifying one line of subprocess stdout.
+///
+/// Extracted out of the stdout reader thread in [`RalphRunner::start`] so
+/// the parsing logic can be exercised with synthetic input, without a real
+/// `ralph_loop.sh` child process.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunnerEvent {
+    /// A `[MESSAGE] {json}` line whose payload parsed successfully.
+    ParsedMessage(Message),
+    /// A `[MESSAGE]` line whose payload was truncated or otherwise invalid JSON.
+    MalformedMessage,
+    /// A `[CONTROL_ACK] {json}` line confirming the subprocess applied a command.
+    ControlAck(ControlCommand),
+    /// Any other non-blank line, to be turned into a log entry.
+    Log(LogEntry),
+    /// A blank or whitespace-only line, dropped.
+    Empty,
+}
+
+/// Classify a single raw line of subprocess stdout.
+///
+/// This is a pure function with no side effects so it can be fuzzed with
+/// thousands of generated lines in a test without spawning anything.
+pub fn classify_line(line: &str) -> RunnerEvent {
+    if line.trim().is_empty() {
+        return RunnerEvent::Empty;
+    }
+
+    if line.starts_with("[MESSAGE]") {
+        let json_part = line.trim_start_matches("[MESSAGE]").trim();
+        return match serde_json::from_str::<Message>(json_part) {
+            Ok(msg) => RunnerEvent::ParsedMessage(msg),
+            Err(_) => RunnerEvent::MalformedMessage,
+        };
+    }
+
+    if line.starts_with("[CONTROL_ACK]") {
+        let json_part = line.trim_start_matches("[CONTROL_ACK]").trim();
+        if let Ok(ack) = serde_json::from_str::<ControlCommand>(json_part) {
+            return RunnerEvent::ControlAck(ack);
+        }
+        // A malformed ack still gets logged like any other line rather
+        // than silently dropped.
+    }
+
+    RunnerEvent::Log(LogEntry::parse(line))
+}
@@ -0,0 +1,148 @@
+//! Where the app's log/message bus comes from: a locally spawned
+//! subprocess, or a remote host's RPC bus attached to as a read-only
+//! follower.
+//!
+//! `RalphApp` drives both the same way: it owns the `crossbeam_channel`
+//! receivers and reconstructs `agent_states`, `tasks`, `agent_graph`, and
+//! `Metrics` purely by feeding whatever arrives on them through
+//! `process_messages`, the same as it always has. A [`LogSource`] is only
+//! responsible for getting `LogEntry`/`Message` envelopes onto those
+//! channels and for answering the handful of lifecycle questions the
+//! Control Center asks (`is_running`, `is_paused`, `send_control`, `kill`).
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crossbeam_channel::Sender;
+use tungstenite::connect;
+use tungstenite::Message as WsMessage;
+
+use crate::rpc::RpcFrame;
+use super::control::ControlCommand;
+use super::events::LogEntry;
+use super::messages::Message;
+use super::runner::RalphRunner;
+
+/// Feeds `RalphApp`'s log/message channels and answers the Control
+/// Center's lifecycle questions, whatever the underlying transport.
+pub trait LogSource: Send {
+    /// Whether the swarm behind this source is still active.
+    fn is_running(&self) -> bool;
+    /// Whether the swarm has acknowledged a pause request.
+    fn is_paused(&self) -> bool;
+    /// Send a pause/resume/flush/agent-settings command upstream, if this
+    /// source supports it. Read-only sources (a follower attached to
+    /// someone else's run) reject every command.
+    fn send_control(&self, cmd: &ControlCommand) -> Result<(), String>;
+    /// Tear the source down: kill the local subprocess, or disconnect
+    /// from the remote host.
+    fn kill(&self);
+}
+
+impl LogSource for RalphRunner {
+    fn is_running(&self) -> bool {
+        RalphRunner::is_running(self)
+    }
+
+    fn is_paused(&self) -> bool {
+        RalphRunner::is_paused(self)
+    }
+
+    fn send_control(&self, cmd: &ControlCommand) -> Result<(), String> {
+        RalphRunner::send_control(self, cmd)
+    }
+
+    fn kill(&self) {
+        RalphRunner::kill(self)
+    }
+}
+
+/// A read-only follower attached to another instance's run over the
+/// `ralphd` websocket RPC bus (see `crate::rpc`). Replays the host's
+/// `RpcFrame` stream onto the app's own `log_sender`/`msg_sender`, so
+/// `process_messages` reconstructs state exactly as it would for a
+/// locally spawned subprocess.
+pub struct ConnectedSource {
+    addr: String,
+    running: Arc<Mutex<bool>>,
+}
+
+impl ConnectedSource {
+    /// Connect to `addr` (a `host:port`, without the `ws://` scheme) and
+    /// start forwarding every replayed and live frame onto `log_sender`
+    /// and `msg_sender`. The websocket handshake runs on the calling
+    /// thread; the catch-up replay and live frames are read on a
+    /// background thread.
+    pub fn connect(addr: String, log_sender: Sender<LogEntry>, msg_sender: Sender<Message>) -> Result<Self, String> {
+        let url = format!("ws://{addr}");
+        let (socket, _response) = connect(&url).map_err(|e| format!("Failed to connect to {addr}: {e}"))?;
+
+        let running = Arc::new(Mutex::new(true));
+        let running_thread = running.clone();
+
+        thread::spawn(move || {
+            let mut socket = socket;
+            loop {
+                let msg = match socket.read() {
+                    Ok(msg) => msg,
+                    Err(_) => break,
+                };
+                if !*running_thread.lock().unwrap() {
+                    let _ = socket.close(None);
+                    break;
+                }
+                let WsMessage::Text(text) = msg else { continue };
+                let Ok(frame) = RpcFrame::decode(&text) else { continue };
+                if dispatch_frame(frame, &log_sender, &msg_sender) {
+                    break;
+                }
+            }
+            *running_thread.lock().unwrap() = false;
+        });
+
+        Ok(Self { addr, running })
+    }
+}
+
+/// Forward one frame onto the app's channels, unpacking a catch-up
+/// `Replay` into its constituent frames. Returns `true` if the frame
+/// signals the run has finished, so the reader thread can stop.
+fn dispatch_frame(frame: RpcFrame, log_sender: &Sender<LogEntry>, msg_sender: &Sender<Message>) -> bool {
+    match frame {
+        RpcFrame::Replay(frames) => frames
+            .into_iter()
+            .fold(false, |terminal, inner| dispatch_frame(inner, log_sender, msg_sender) || terminal),
+        RpcFrame::Log(entry) => {
+            let terminal = entry.message.contains("<promise>COMPLETE</promise>");
+            let _ = log_sender.send(entry);
+            terminal
+        }
+        RpcFrame::Message(msg) => {
+            let _ = msg_sender.send(msg);
+            false
+        }
+        // Not yet produced by `ralphd`; reconstructing agent state from
+        // the `Message` stream already covers this.
+        RpcFrame::AgentState { .. } => false,
+    }
+}
+
+impl LogSource for ConnectedSource {
+    fn is_running(&self) -> bool {
+        *self.running.lock().unwrap()
+    }
+
+    fn is_paused(&self) -> bool {
+        false
+    }
+
+    fn send_control(&self, _cmd: &ControlCommand) -> Result<(), String> {
+        Err(format!("read-only follower of {}; control commands aren't supported", self.addr))
+    }
+
+    /// Marks the source stopped; the reader thread closes the socket and
+    /// stops forwarding when the next frame from the host arrives.
+    fn kill(&self) {
+        *self.running.lock().unwrap() = false;
+    }
+}
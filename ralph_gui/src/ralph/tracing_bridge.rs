@@ -0,0 +1,81 @@
+//! Bridges `tracing` events into the `LogEntry` channel
+//!
+//! `RalphRunner`'s stdout/stderr readers already push `LogEntry`s (stdout
+//! lines via [`classify_line`](super::classify_line)) onto a
+//! per-run channel; this layer lets anything instrumented with
+//! `tracing::info!`/`tracing::error!` (in-process code, not the
+//! subprocess) land in the same log viewer instead of a separate
+//! stream, by forwarding each event onto whichever channel the
+//! currently running source points it at.
+
+use std::sync::{Mutex, OnceLock};
+
+use crossbeam_channel::Sender;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+
+use super::events::{LogEntry, LogLevel};
+
+/// Events logged under this target are treated as agent "thoughts"
+/// rather than ordinary log lines, mirroring the `<think>` heuristic
+/// `LogEntry::parse` applies to raw subprocess output.
+pub const THOUGHT_TARGET: &str = "ralph::thought";
+
+fn sender_slot() -> &'static Mutex<Option<Sender<LogEntry>>> {
+    static SLOT: OnceLock<Mutex<Option<Sender<LogEntry>>>> = OnceLock::new();
+    SLOT.get_or_init(|| Mutex::new(None))
+}
+
+/// Point the bridge at a fresh run's `LogEntry` channel, replacing
+/// whatever the previous run (if any) was using. Called whenever a new
+/// local run starts or a new host connection is made.
+pub fn set_sender(sender: Sender<LogEntry>) {
+    *sender_slot().lock().unwrap() = Some(sender);
+}
+
+/// Install the global `tracing` subscriber: a terminal formatter for
+/// whoever's watching the process directly, plus this bridge layer.
+/// Call once at startup, before the first run starts.
+pub fn install() {
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .with(LogBridgeLayer)
+        .init();
+}
+
+struct LogBridgeLayer;
+
+impl<S: Subscriber> Layer<S> for LogBridgeLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let Some(sender) = sender_slot().lock().unwrap().clone() else {
+            return;
+        };
+
+        let mut message = String::new();
+        event.record(&mut MessageVisitor(&mut message));
+
+        let level = if event.metadata().target() == THOUGHT_TARGET {
+            LogLevel::Thought
+        } else {
+            match *event.metadata().level() {
+                Level::ERROR | Level::WARN => LogLevel::Error,
+                Level::INFO => LogLevel::Info,
+                Level::DEBUG | Level::TRACE => LogLevel::System,
+            }
+        };
+
+        let _ = sender.send(LogEntry::new(level, message));
+    }
+}
+
+struct MessageVisitor<'a>(&'a mut String);
+
+impl Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.push_str(&format!("{value:?}"));
+        }
+    }
+}
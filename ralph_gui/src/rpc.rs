@@ -0,0 +1,61 @@
+//! Wire protocol for the headless daemon's websocket RPC bus
+//!
+//! Every envelope the daemon's `crossbeam` log/message bus produces is
+//! wrapped in an [`RpcFrame`] and sent to attached clients as one
+//! websocket message carrying its JSON encoding. A [`ReplayBuffer`] keeps
+//! recent frames so a late-joining client can catch up before it starts
+//! receiving the live stream.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::app::Agent;
+use crate::ralph::{AgentState, LogEntry, Message};
+
+/// Maximum number of frames kept for late-joining clients
+const REPLAY_CAPACITY: usize = 500;
+
+/// A single message on the RPC bus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RpcFrame {
+    Log(LogEntry),
+    Message(Message),
+    AgentState { agent: Agent, state: AgentState },
+    /// Sent once right after a client connects, carrying recent history
+    Replay(Vec<RpcFrame>),
+}
+
+impl RpcFrame {
+    /// Encode this frame as a JSON string, the payload of one websocket
+    /// text/binary message.
+    pub fn encode(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Decode a frame from a received websocket message payload.
+    pub fn decode(raw: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(raw)
+    }
+}
+
+/// Bounded ring buffer of recently broadcast frames, replayed to clients
+/// that attach after the run has already started.
+#[derive(Debug, Default)]
+pub struct ReplayBuffer {
+    frames: VecDeque<RpcFrame>,
+}
+
+impl ReplayBuffer {
+    pub fn push(&mut self, frame: RpcFrame) {
+        self.frames.push_back(frame);
+        if self.frames.len() > REPLAY_CAPACITY {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Snapshot the buffer as a single `Replay` frame for a new client.
+    pub fn snapshot(&self) -> RpcFrame {
+        RpcFrame::Replay(self.frames.iter().cloned().collect())
+    }
+}
@@ -0,0 +1,166 @@
+//! Semantic-ish search over logged text
+//!
+//! Every log/thought entry is chunked into ~200-character windows and
+//! embedded into a fixed-size vector at insert time; a query is embedded
+//! the same way and scored against the index by cosine similarity. All
+//! vectors are L2-normalized on the way in, so similarity is a plain dot
+//! product at search time.
+//!
+//! The default embedder has no network dependency: it hashes character
+//! trigrams and whole words into a fixed 512-dim space (a "hashed
+//! bag-of-words" embedding). It's behind the `Embedder` trait so a real
+//! model-backed embedding service can be swapped in later without
+//! touching `SearchIndex`.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Dimensionality of vectors produced by `HashedTrigramEmbedder`.
+pub const EMBED_DIMS: usize = 512;
+
+/// Length, in characters, of the windows `SearchIndex::index` chunks
+/// text into before embedding.
+const CHUNK_CHARS: usize = 200;
+
+/// Turns text into a fixed-length vector. Implementations are expected
+/// to return an L2-normalized vector (the zero vector for empty input).
+pub trait Embedder {
+    fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+/// No-network default: hashes character trigrams and words into
+/// `EMBED_DIMS` buckets and L2-normalizes the result.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HashedTrigramEmbedder;
+
+impl Embedder for HashedTrigramEmbedder {
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0f32; EMBED_DIMS];
+        let lower = text.to_lowercase();
+        let chars: Vec<char> = lower.chars().collect();
+
+        for trigram in chars.windows(3) {
+            bump(&mut vector, trigram);
+        }
+        for word in lower.split_whitespace() {
+            bump(&mut vector, word);
+        }
+
+        normalize(&mut vector);
+        vector
+    }
+}
+
+fn bump<T: Hash>(vector: &mut [f32], key: T) {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let bucket = (hasher.finish() as usize) % vector.len();
+    vector[bucket] += 1.0;
+}
+
+/// L2-normalize in place. Leaves the all-zero vector (empty/whitespace
+/// input) untouched rather than dividing by a zero norm.
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for v in vector.iter_mut() {
+            *v /= norm;
+        }
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Split `text` into `window`-character slices (the last one possibly
+/// shorter). Splits on `char_indices` so multi-byte UTF-8 is never cut
+/// mid-codepoint.
+fn chunk_chars(text: &str, window: usize) -> Vec<&str> {
+    let boundaries: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    if boundaries.is_empty() {
+        return Vec::new();
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < boundaries.len() {
+        let end = (start + window).min(boundaries.len());
+        let byte_start = boundaries[start];
+        let byte_end = boundaries.get(end).copied().unwrap_or(text.len());
+        chunks.push(&text[byte_start..byte_end]);
+        start = end;
+    }
+    chunks
+}
+
+/// Embedded, searchable view over the log stream. Each indexed chunk is
+/// tagged with the stable id of the log entry it came from (not its
+/// position in the live log buffer, which shifts as old entries are
+/// evicted) — see `RalphApp::log_position`.
+pub struct SearchIndex<E: Embedder = HashedTrigramEmbedder> {
+    embedder: E,
+    entries: Vec<(Vec<f32>, usize)>,
+}
+
+impl Default for SearchIndex<HashedTrigramEmbedder> {
+    fn default() -> Self {
+        Self::new(HashedTrigramEmbedder)
+    }
+}
+
+impl<E: Embedder> SearchIndex<E> {
+    pub fn new(embedder: E) -> Self {
+        Self { embedder, entries: Vec::new() }
+    }
+
+    /// Chunk `text` and embed each chunk, tagging every resulting vector
+    /// with `log_index`. No-op for blank text.
+    pub fn index(&mut self, log_index: usize, text: &str) {
+        if text.trim().is_empty() {
+            return;
+        }
+        for chunk in chunk_chars(text, CHUNK_CHARS) {
+            self.entries.push((self.embedder.embed(chunk), log_index));
+        }
+    }
+
+    /// Top `top_k` log indices by cosine similarity to `query`, highest
+    /// first. A log index appears at most once, scored by its
+    /// best-matching chunk. Empty for a blank query.
+    pub fn search(&self, query: &str, top_k: usize) -> Vec<(usize, f32)> {
+        let query_vector = self.embedder.embed(query);
+        if query_vector.iter().all(|&v| v == 0.0) {
+            return Vec::new();
+        }
+
+        let mut best: HashMap<usize, f32> = HashMap::new();
+        for (vector, log_index) in &self.entries {
+            let score = dot(&query_vector, vector);
+            best.entry(*log_index)
+                .and_modify(|existing| {
+                    if score > *existing {
+                        *existing = score;
+                    }
+                })
+                .or_insert(score);
+        }
+
+        let mut results: Vec<(usize, f32)> = best.into_iter().collect();
+        results.sort_by(|a, b| b.1.total_cmp(&a.1));
+        results.truncate(top_k);
+        results
+    }
+
+    /// Drop chunks tagged with a log index older than `min_index`, e.g.
+    /// once the matching entry has scrolled out of the capped log
+    /// buffer.
+    pub fn prune_before(&mut self, min_index: usize) {
+        self.entries.retain(|(_, log_index)| *log_index >= min_index);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -26,14 +26,47 @@ mod tests {
         
         let mut graph = ForceGraph::new(Pos2::new(0.0, 0.0));
         let initial_pos = graph.nodes[&Agent::Orchestrator].pos;
-        
-        graph.update(0.1);
+
+        graph.update(0.1, 0.1);
         let new_pos = graph.nodes[&Agent::Orchestrator].pos;
         
         // Positions should have moved due to repulsion
         assert_ne!(initial_pos, new_pos);
     }
 
+    #[test]
+    fn test_graph_edge_decay_and_pulse() {
+        use crate::ui::graph::{Edge, ForceGraph};
+        use egui::Pos2;
+
+        let mut graph = ForceGraph::new(Pos2::new(0.0, 0.0));
+        graph.edges.push(Edge {
+            from: Agent::Orchestrator,
+            to: Agent::Engineer,
+            strength: 1.0,
+            last_pulse: 0.0,
+        });
+
+        // Fresh edge: full pulse, no decay yet.
+        graph.update(0.0, 0.0);
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.pulse_intensity(&graph.edges[0]), 1.0);
+
+        // A little later the pulse has faded but the edge is still warm.
+        graph.update(0.1, 1.0);
+        assert_eq!(graph.edges.len(), 1);
+        assert!(graph.edges[0].strength < 1.0);
+        assert!(graph.pulse_intensity(&graph.edges[0]) < 1.0);
+
+        // Long enough idle and the edge cools below the drop threshold.
+        let mut now = 1.0;
+        for _ in 0..200 {
+            now += 0.1;
+            graph.update(0.1, now);
+        }
+        assert!(graph.edges.is_empty());
+    }
+
     #[test]
     fn test_message_parsing() {
         use crate::ralph::messages::{Message, MessageType};
@@ -73,4 +106,88 @@ mod tests {
         
         assert_eq!(app.agent_params.get(&orchestrator).unwrap().temperature, 1.5);
     }
+
+    /// Randomized harness for the stdout classification path used by
+    /// `RalphRunner::start`'s reader thread, in the spirit of Zed's
+    /// operation-script fuzz tests: feed `classify_line` a seeded, weighted
+    /// mix of valid `[MESSAGE]` JSON, garbled JSON, blank lines, and
+    /// ANSI/plain log lines, then check the invariants the reader thread
+    /// relies on hold across thousands of seeds. The seed is printed on
+    /// failure so a bad case can be reproduced directly.
+    #[test]
+    fn test_classify_line_randomized() {
+        use crate::ralph::{classify_line, messages::Message, LogEntry, RunnerEvent};
+
+        for seed in 0..4000u64 {
+            let rng = fastrand::Rng::with_seed(seed);
+            let mut lines = Vec::new();
+            let mut expected_messages = 0usize;
+            let mut expected_logs = 0usize;
+
+            for _ in 0..40 {
+                let line = match rng.u32(0..100) {
+                    0..=29 => {
+                        // Valid `[MESSAGE]` JSON.
+                        let msg = Message::status(if rng.bool() { "ok" } else { "busy" });
+                        expected_messages += 1;
+                        expected_logs += 1;
+                        format!("[MESSAGE] {}", serde_json::to_string(&msg).unwrap())
+                    }
+                    30..=44 => {
+                        // Truncated/garbled JSON under the `[MESSAGE]` tag.
+                        expected_logs += 1;
+                        format!("[MESSAGE] {{\"id\": \"{}\", \"type\": \"status\"", rng.u32(..))
+                    }
+                    45..=59 => {
+                        // Blank/whitespace-only line.
+                        " ".repeat(rng.usize(0..4))
+                    }
+                    60..=79 => {
+                        // ANSI-colored log line.
+                        expected_logs += 1;
+                        format!("\x1b[3{}m[Swarm] Engineer iteration {}\x1b[0m", rng.u8(1..7), rng.u32(..))
+                    }
+                    _ => {
+                        // Interleaved stderr-style line.
+                        expected_logs += 1;
+                        format!("ERROR: task {} failed", rng.u32(..))
+                    }
+                };
+                lines.push(line);
+            }
+
+            let (log_tx, log_rx) = crossbeam_channel::unbounded();
+            let (msg_tx, msg_rx) = crossbeam_channel::unbounded();
+
+            for line in &lines {
+                match classify_line(line) {
+                    RunnerEvent::ParsedMessage(msg) => {
+                        let _ = msg_tx.send(msg);
+                        let _ = log_tx.send(LogEntry::parse(line));
+                    }
+                    RunnerEvent::MalformedMessage => {
+                        let _ = log_tx.send(LogEntry::parse(line));
+                    }
+                    RunnerEvent::Log(entry) => {
+                        let _ = log_tx.send(entry);
+                    }
+                    RunnerEvent::ControlAck(_) => {}
+                    RunnerEvent::Empty => {}
+                }
+            }
+            drop(log_tx);
+            drop(msg_tx);
+
+            let logs: Vec<_> = log_rx.try_iter().collect();
+            let msgs: Vec<_> = msg_rx.try_iter().collect();
+
+            assert_eq!(msgs.len(), expected_messages, "seed {seed}: message count mismatch, lines={lines:?}");
+            assert_eq!(logs.len(), expected_logs, "seed {seed}: log count mismatch, lines={lines:?}");
+
+            let non_blank: Vec<&String> = lines.iter().filter(|l| !l.trim().is_empty()).collect();
+            for (entry, original) in logs.iter().zip(non_blank.iter()) {
+                assert_eq!(entry.message.as_str(), original.trim(), "seed {seed}: log ordering broken");
+            }
+        }
+    }
 }
\ No newline at end of file
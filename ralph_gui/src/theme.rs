@@ -24,6 +24,9 @@ pub const ACCENT: Color32 = Color32::from_rgb(123, 44, 191);       // #7b2cbf pu
 pub const CONNECTION_ACTIVE: Color32 = Color32::from_rgb(233, 69, 96);
 pub const CONNECTION_IDLE: Color32 = Color32::from_rgb(60, 60, 80);
 
+/// Background wash for a log entry jumped to from a search result.
+pub const LOG_HIGHLIGHT: Color32 = Color32::from_rgba_premultiplied(46, 18, 24, 80);
+
 /// Create dark visuals for the app
 pub fn dark_visuals() -> Visuals {
     let mut visuals = Visuals::dark();
@@ -0,0 +1,145 @@
+//! Minimal byte-pair-encoding tokenizer, in the shape of `tiktoken-rs`'s
+//! `CoreBPE`: a merges/rank table loaded once, plus a greedy encoder that
+//! repeatedly merges the lowest-rank adjacent byte pair until none apply.
+//!
+//! This ships a small built-in merge table approximating common English
+//! subwords rather than vendoring a real `cl100k_base.tiktoken` rank file
+//! (hundreds of KB), so token counts here are an estimate for cost/usage
+//! metering, not a byte-exact match to any particular model's tokenizer.
+
+use std::collections::HashMap;
+
+/// A loaded BPE rank table plus the encoder that walks it.
+#[derive(Debug, Clone)]
+pub struct CoreBpe {
+    /// Byte sequence -> token id. Lower ids were merged first, mirroring
+    /// tiktoken's "rank" ordering (merge lowest rank first).
+    ranks: HashMap<Vec<u8>, u32>,
+}
+
+impl CoreBpe {
+    /// Build a `CoreBpe` from an explicit `(bytes, rank)` merge table.
+    pub fn new(merges: &[(&[u8], u32)]) -> Self {
+        Self {
+            ranks: merges.iter().map(|&(bytes, rank)| (bytes.to_vec(), rank)).collect(),
+        }
+    }
+
+    /// A compact, built-in approximation of an English BPE vocabulary:
+    /// common letter pairs and short words merge first, longer runs merge
+    /// later, single bytes fall back to their own token.
+    pub fn english_approx() -> Self {
+        Self::new(BUILTIN_MERGES)
+    }
+
+    /// Encode `text` into token ids by repeatedly merging the
+    /// lowest-rank adjacent pair until no merge in the table applies.
+    pub fn encode(&self, text: &str) -> Vec<u32> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+
+        let mut parts: Vec<Vec<u8>> = text.bytes().map(|b| vec![b]).collect();
+
+        loop {
+            let mut best: Option<(usize, u32)> = None;
+
+            for i in 0..parts.len().saturating_sub(1) {
+                let mut merged = parts[i].clone();
+                merged.extend_from_slice(&parts[i + 1]);
+                if let Some(&rank) = self.ranks.get(&merged) {
+                    let is_better = match best {
+                        Some((_, best_rank)) => rank < best_rank,
+                        None => true,
+                    };
+                    if is_better {
+                        best = Some((i, rank));
+                    }
+                }
+            }
+
+            let Some((i, _)) = best else { break };
+            let mut merged = parts[i].clone();
+            merged.extend_from_slice(&parts[i + 1]);
+            parts.splice(i..=i + 1, [merged]);
+        }
+
+        parts
+            .iter()
+            .map(|bytes| {
+                self.ranks.get(bytes).copied().unwrap_or_else(|| {
+                    // Unmerged single bytes keep their raw byte value as
+                    // their id, the same convention tiktoken uses for the
+                    // 0..256 base vocabulary.
+                    bytes.first().copied().unwrap_or(0) as u32
+                })
+            })
+            .collect()
+    }
+
+    /// Number of tokens `text` encodes to, i.e. `self.encode(text).len()`.
+    pub fn count(&self, text: &str) -> usize {
+        self.encode(text).len()
+    }
+}
+
+impl Default for CoreBpe {
+    fn default() -> Self {
+        Self::english_approx()
+    }
+}
+
+/// `(byte sequence, rank)` pairs, lowest rank merges first. Built by hand
+/// from common English digraphs, trigraphs, and short words rather than a
+/// trained vocabulary.
+const BUILTIN_MERGES: &[(&[u8], u32)] = &[
+    (b"th", 0),
+    (b"he", 1),
+    (b"in", 2),
+    (b"er", 3),
+    (b"an", 4),
+    (b"re", 5),
+    (b"on", 6),
+    (b"at", 7),
+    (b"en", 8),
+    (b"nd", 9),
+    (b"ti", 10),
+    (b"es", 11),
+    (b"or", 12),
+    (b"te", 13),
+    (b"of", 14),
+    (b"ed", 15),
+    (b"is", 16),
+    (b"it", 17),
+    (b"al", 18),
+    (b"ar", 19),
+    (b"st", 20),
+    (b"to", 21),
+    (b"nt", 22),
+    (b"ng", 23),
+    (b"se", 24),
+    (b"ha", 25),
+    (b"as", 26),
+    (b"ou", 27),
+    (b"io", 28),
+    (b"le", 29),
+    (b"ve", 30),
+    (b" t", 31),
+    (b" a", 32),
+    (b" s", 33),
+    (b" w", 34),
+    (b" the", 40),
+    (b"the", 41),
+    (b" and", 42),
+    (b"and", 43),
+    (b" to", 44),
+    (b" of", 45),
+    (b" in", 46),
+    (b" a ", 47),
+    (b"ing", 48),
+    (b"ion", 49),
+    (b"ent", 50),
+    (b"tion", 60),
+    (b"ment", 61),
+    (b"ould", 62),
+];
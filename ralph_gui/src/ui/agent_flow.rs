@@ -11,6 +11,10 @@ use crate::theme;
 const NODE_RADIUS: f32 = 28.0;
 const GLOW_RADIUS: f32 = 36.0;
 
+/// How long, in animation-clock seconds, an edge stays drawn "active"
+/// after it last carried a message.
+const EDGE_ACTIVE_WINDOW: f32 = 1.5;
+
 /// Show the agent flow visualization
 pub fn show(ui: &mut egui::Ui, app: &RalphApp) {
     ui.horizontal(|ui| {
@@ -70,6 +74,9 @@ fn draw_connections(painter: &Painter, positions: &[(Agent, Pos2)], app: &RalphA
         positions.iter().find(|(a, _)| *a == agent).map(|(_, p)| *p).unwrap_or_default()
     };
 
+    let recent_edges = app.agent_graph.recent_edges(app.animation_time, EDGE_ACTIVE_WINDOW);
+    let is_edge_active = |from: Agent, to: Agent| recent_edges.iter().any(|&(f, t, _)| f == from && t == to);
+
     // Define connections: (from, to)
     let connections = [
         (Agent::Translator, Agent::Orchestrator),
@@ -82,7 +89,7 @@ fn draw_connections(painter: &Painter, positions: &[(Agent, Pos2)], app: &RalphA
         let from_pos = get_pos(from);
         let to_pos = get_pos(to);
 
-        let is_active = app.active_connection == Some((from, to));
+        let is_active = is_edge_active(from, to);
 
         let color = if is_active {
             theme::CONNECTION_ACTIVE
@@ -108,19 +115,10 @@ fn draw_connections(painter: &Painter, positions: &[(Agent, Pos2)], app: &RalphA
     let eng_pos = get_pos(Agent::Engineer);
     let des_pos = get_pos(Agent::Designer);
 
-    // Check if either direction is active
-    let is_bidir_active = app.active_connection == Some((Agent::Engineer, Agent::Designer))
-        || app.active_connection == Some((Agent::Designer, Agent::Engineer));
-
-    let color = if is_bidir_active {
-        theme::CONNECTION_ACTIVE
-    } else {
-        theme::CONNECTION_IDLE
-    };
-
-    // Already drawn above, just add reverse arrow if active
-    if is_bidir_active && app.active_connection == Some((Agent::Designer, Agent::Engineer)) {
-        draw_arrow_head(painter, des_pos, eng_pos, color);
+    // Already drawn above (Engineer -> Designer); add the reverse arrow
+    // too if messages have recently flowed the other way.
+    if is_edge_active(Agent::Designer, Agent::Engineer) {
+        draw_arrow_head(painter, des_pos, eng_pos, theme::CONNECTION_ACTIVE);
     }
 }
 
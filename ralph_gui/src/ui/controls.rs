@@ -2,6 +2,7 @@
 
 use egui::RichText;
 use crate::app::{RalphApp, Agent};
+use crate::ralph::{ControlCommand, LogEntry};
 use crate::theme;
 
 pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
@@ -21,11 +22,16 @@ pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
             }
         }
 
-        // Pause button (toggle)
-        let pause_label = if app.is_paused { "Resume" } else { "Pause" };
+        // Pause button (toggle); label reflects the subprocess's
+        // acknowledged state, not an optimistic local flag.
+        let is_paused = app.is_paused();
+        let pause_label = if is_paused { "Resume" } else { "Pause" };
         if ui.add_enabled(is_running, egui::Button::new(pause_label)).clicked() {
-            app.is_paused = !app.is_paused;
-            // TODO: Send pause message to bus
+            let cmd = if is_paused { ControlCommand::Resume } else { ControlCommand::Pause };
+            let result = app.runner().map(|r| r.send_control(&cmd));
+            if let Some(Err(e)) = result {
+                app.add_log(LogEntry::error(format!("Failed to send control command: {e}")));
+            }
         }
 
         // Stop button
@@ -37,8 +43,12 @@ pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
 
         // Flush button
         if ui.button("Flush").on_hover_text("Emergency Flush: Clear diagnostic bus").clicked() {
-            // TODO: Implementation for emergency flush
-            app.add_log(crate::ralph::LogEntry::system("Emergency bus flush requested".to_string()));
+            let result = app.runner().map(|r| r.send_control(&ControlCommand::Flush));
+            match result {
+                Some(Ok(())) => app.add_log(LogEntry::system("Emergency bus flush requested".to_string())),
+                Some(Err(e)) => app.add_log(LogEntry::error(format!("Failed to send control command: {e}"))),
+                None => app.add_log(LogEntry::system("Emergency bus flush requested (no active run)".to_string())),
+            }
         }
     });
 
@@ -48,7 +58,7 @@ pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
     ui.horizontal(|ui| {
         ui.label("Sandbox Mode:");
         if ui.checkbox(&mut app.sandbox_enabled, "").on_hover_text("Run agents inside a Docker container").changed() {
-             app.add_log(crate::ralph::LogEntry::system(format!("Sandbox mode toggled: {}", app.sandbox_enabled)));
+             app.add_log(LogEntry::system(format!("Sandbox mode toggled: {}", app.sandbox_enabled)));
         }
     });
 
@@ -62,12 +72,30 @@ pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
                 let mut enabled = app.enabled_agents.get(agent).copied().unwrap_or(true);
                 if ui.checkbox(&mut enabled, "Enabled").changed() {
                     app.enabled_agents.insert(*agent, enabled);
+                    let cmd = ControlCommand::SetAgentEnabled { agent: agent.name().to_string(), enabled };
+                    let result = app.runner().map(|r| r.send_control(&cmd));
+                    if let Some(Err(e)) = result {
+                        app.add_log(LogEntry::error(format!("Failed to send control command: {e}")));
+                    }
                 }
             });
 
+            let temperature_range = app.temperature_range();
+            let top_p_range = app.top_p_range();
+            let mut changed_params = None;
             if let Some(params) = app.agent_params.get_mut(agent) {
-                ui.add(egui::Slider::new(&mut params.temperature, 0.0..=2.0).text("Temp"));
-                ui.add(egui::Slider::new(&mut params.top_p, 0.0..=1.0).text("Top P"));
+                let temp_changed = ui.add(egui::Slider::new(&mut params.temperature, temperature_range).text("Temp")).changed();
+                let top_p_changed = ui.add(egui::Slider::new(&mut params.top_p, top_p_range).text("Top P")).changed();
+                if temp_changed || top_p_changed {
+                    changed_params = Some((params.temperature, params.top_p));
+                }
+            }
+            if let Some((temperature, top_p)) = changed_params {
+                let cmd = ControlCommand::SetParams { agent: agent.name().to_string(), temperature, top_p };
+                let result = app.runner().map(|r| r.send_control(&cmd));
+                if let Some(Err(e)) = result {
+                    app.add_log(LogEntry::error(format!("Failed to send control command: {e}")));
+                }
             }
         });
     }
@@ -13,6 +13,9 @@ pub struct Node {
     pub mass: f32,
 }
 
+/// Edge strength below which it's considered cold and dropped.
+const MIN_STRENGTH: f32 = 0.01;
+
 /// An edge representing communication between agents
 #[derive(Debug, Clone)]
 pub struct Edge {
@@ -22,12 +25,24 @@ pub struct Edge {
     pub last_pulse: f32, // timestamp of last communication
 }
 
+impl Edge {
+    /// How strongly this edge should glow right now, in `[0, 1]`. 1 right
+    /// at `last_pulse`, fading linearly to 0 over `pulse_window` seconds.
+    pub fn pulse_intensity(&self, now: f32, pulse_window: f32) -> f32 {
+        (1.0 - (now - self.last_pulse) / pulse_window).max(0.0)
+    }
+}
+
 /// Force-directed graph simulation
 pub struct ForceGraph {
     pub nodes: HashMap<Agent, Node>,
     pub edges: Vec<Edge>,
     pub center: Pos2,
     pub config: GraphConfig,
+    /// `now` passed to the last `update()` call, kept so callers can ask
+    /// for an edge's current pulse intensity without re-threading the
+    /// animation clock through every render call.
+    pub last_update_at: f32,
 }
 
 pub struct GraphConfig {
@@ -35,6 +50,10 @@ pub struct GraphConfig {
     pub attraction: f32,
     pub damping: f32,
     pub ideal_length: f32,
+    /// Per-second exponential decay applied to edge `strength` while idle.
+    pub decay_rate: f32,
+    /// Window, in seconds, over which `Edge::pulse_intensity` fades to 0.
+    pub pulse_window: f32,
 }
 
 impl Default for GraphConfig {
@@ -44,6 +63,8 @@ impl Default for GraphConfig {
             attraction: 0.05,
             damping: 0.95,
             ideal_length: 120.0,
+            decay_rate: 0.8,
+            pulse_window: 1.5,
         }
     }
 }
@@ -65,10 +86,28 @@ impl ForceGraph {
             edges: Vec::new(),
             center,
             config: GraphConfig::default(),
+            last_update_at: 0.0,
         }
     }
 
-    pub fn update(&mut self, dt: f32) {
+    /// Current pulse intensity of `edge`, as of the last `update()` call.
+    pub fn pulse_intensity(&self, edge: &Edge) -> f32 {
+        edge.pulse_intensity(self.last_update_at, self.config.pulse_window)
+    }
+
+    /// Advance the simulation by `dt` seconds, decaying edge strength
+    /// over `dt` and dropping edges that have gone cold. `now` is the
+    /// app's monotonic `animation_time`, recorded so `pulse_intensity`
+    /// is computed as of this update.
+    pub fn update(&mut self, dt: f32, now: f32) {
+        self.last_update_at = now;
+
+        // Decay edge strength and drop edges that have gone cold.
+        for edge in &mut self.edges {
+            edge.strength *= (-self.config.decay_rate * dt).exp();
+        }
+        self.edges.retain(|edge| edge.strength >= MIN_STRENGTH);
+
         let agents = crate::app::Agent::all();
         let mut forces: HashMap<Agent, Vec2> = agents.iter().map(|a| (*a, Vec2::ZERO)).collect();
 
@@ -104,8 +143,11 @@ impl ForceGraph {
             
             let diff = pos_b - pos_a;
             let dist = diff.length();
-            let force = diff.normalized() * (dist - self.config.ideal_length) * self.config.attraction;
-            
+            let force = diff.normalized()
+                * (dist - self.config.ideal_length)
+                * self.config.attraction
+                * edge.strength;
+
             *forces.get_mut(&edge.from).unwrap() += force;
             *forces.get_mut(&edge.to).unwrap() -= force;
         }
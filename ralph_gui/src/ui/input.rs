@@ -35,4 +35,21 @@ pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
             app.start_run(objective);
         }
     });
+
+    ui.add_space(4.0);
+
+    // Attach to another instance's run as a read-only follower, instead
+    // of spawning a local subprocess.
+    ui.horizontal(|ui| {
+        ui.add_sized(
+            [ui.available_width() - 100.0, 24.0],
+            egui::TextEdit::singleline(&mut app.host_input).hint_text("host:port to follow..."),
+        );
+
+        let can_connect = !app.host_input.trim().is_empty() && !app.is_running();
+        if ui.add_enabled(can_connect, egui::Button::new("Follow")).clicked() {
+            let addr = app.host_input.trim().to_string();
+            app.connect_to_host(addr);
+        }
+    });
 }
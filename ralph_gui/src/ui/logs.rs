@@ -5,6 +5,16 @@ use crate::app::RalphApp;
 use crate::ralph::{LogEntry, LogLevel};
 use crate::theme;
 
+/// Every level a toggle chip exists for, in display order.
+const LEVELS: [LogLevel; 6] = [
+    LogLevel::Info,
+    LogLevel::Agent,
+    LogLevel::Error,
+    LogLevel::Success,
+    LogLevel::Thought,
+    LogLevel::System,
+];
+
 pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
     ui.horizontal(|ui| {
         ui.heading(RichText::new("Logs").color(theme::TEXT_PRIMARY));
@@ -14,63 +24,126 @@ pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
                 app.logs.clear();
             }
 
-            ui.checkbox(&mut app.show_system_logs, "System");
+            if ui.button("Export").clicked() {
+                let visible: Vec<&LogEntry> = visible_entries(app).collect();
+                match app.export_logs(&visible) {
+                    Ok(path) => app.add_log(LogEntry::system(format!("Exported {} log entries to {}", visible.len(), path.display()))),
+                    Err(e) => app.add_log(LogEntry::error(format!("Failed to export logs: {e}"))),
+                }
+            }
+
+            for level in LEVELS {
+                let mut shown = app.visible_log_levels.contains(&level);
+                if ui.checkbox(&mut shown, level_label(level)).changed() {
+                    if shown {
+                        app.visible_log_levels.insert(level);
+                    } else {
+                        app.visible_log_levels.remove(&level);
+                    }
+                }
+            }
         });
     });
 
+    ui.add_space(4.0);
+
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Filter").color(theme::TEXT_MUTED).small());
+        ui.add(egui::TextEdit::singleline(&mut app.log_filter_query).desired_width(200.0));
+    });
+
     ui.add_space(8.0);
 
+    // A search selection or an active text filter should be scrolled
+    // through, not have the viewer keep snapping back to the newest entry
+    // underneath it.
+    let sticky = app.highlighted_log.is_none() && app.log_filter_query.trim().is_empty();
+
     ScrollArea::vertical()
         .auto_shrink([false, false])
-        .stick_to_bottom(true)
+        .stick_to_bottom(sticky)
         .show(ui, |ui| {
-            for entry in app.logs.iter() {
-                // Filter system logs if not showing them
-                if !app.show_system_logs && entry.level == LogLevel::System {
+            for (position, entry) in app.logs.iter().enumerate() {
+                if !entry_visible(app, entry) {
                     continue;
                 }
 
-                log_entry_row(ui, entry);
+                let highlighted = app.is_log_highlighted(position);
+                let response = log_entry_row(ui, entry, highlighted);
+                if highlighted {
+                    response.scroll_to_me(Some(egui::Align::Center));
+                }
             }
         });
 }
 
-fn log_entry_row(ui: &mut egui::Ui, entry: &LogEntry) {
-    ui.horizontal(|ui| {
-        // Timestamp
-        ui.label(
-            RichText::new(&entry.timestamp)
-                .color(theme::TEXT_MUTED)
-                .small()
-                .monospace(),
-        );
-
-        // Level indicator
-        let (level_text, level_color) = match entry.level {
-            LogLevel::Info => ("INFO", theme::TEXT_SECONDARY),
-            LogLevel::System => ("SYS", theme::TEXT_MUTED),
-            LogLevel::Agent => ("AGENT", theme::ACCENT),
-            LogLevel::Error => ("ERR", theme::ERROR),
-            LogLevel::Success => ("OK", theme::SUCCESS),
-            LogLevel::Thought => ("THINK", theme::TEXT_MUTED),
-        };
-
-        ui.label(RichText::new(level_text).color(level_color).small().monospace());
-
-        // Message
-        let msg_color = match entry.level {
-            LogLevel::Error => theme::ERROR,
-            LogLevel::Success => theme::SUCCESS,
-            LogLevel::Agent => theme::AGENT_ACTIVE,
-            LogLevel::Thought => theme::TEXT_SECONDARY,
-            _ => theme::TEXT_PRIMARY,
-        };
-
-        let mut text = RichText::new(&entry.message).color(msg_color);
-        if entry.level == LogLevel::Thought {
-            text = text.italics();
-        }
-
-        ui.label(text);
-    });
+fn level_label(level: LogLevel) -> &'static str {
+    match level {
+        LogLevel::Info => "Info",
+        LogLevel::Agent => "Agent",
+        LogLevel::Error => "Error",
+        LogLevel::Success => "Success",
+        LogLevel::Thought => "Thought",
+        LogLevel::System => "System",
+    }
+}
+
+fn entry_visible(app: &RalphApp, entry: &LogEntry) -> bool {
+    if !app.visible_log_levels.contains(&entry.level) {
+        return false;
+    }
+
+    let query = app.log_filter_query.trim();
+    query.is_empty() || entry.message.to_lowercase().contains(&query.to_lowercase())
+}
+
+fn visible_entries<'a>(app: &'a RalphApp) -> impl Iterator<Item = &'a LogEntry> {
+    app.logs.iter().filter(|entry| entry_visible(app, entry))
+}
+
+fn log_entry_row(ui: &mut egui::Ui, entry: &LogEntry, highlighted: bool) -> egui::Response {
+    let fill = if highlighted { theme::LOG_HIGHLIGHT } else { egui::Color32::TRANSPARENT };
+
+    egui::Frame::default()
+        .fill(fill)
+        .inner_margin(2.0)
+        .show(ui, |ui| {
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    // Timestamp
+                    ui.label(
+                        RichText::new(&entry.timestamp)
+                            .color(theme::TEXT_MUTED)
+                            .small()
+                            .monospace(),
+                    );
+
+                    // Level indicator
+                    let (level_text, level_color) = match entry.level {
+                        LogLevel::Info => ("INFO", theme::TEXT_SECONDARY),
+                        LogLevel::System => ("SYS", theme::TEXT_MUTED),
+                        LogLevel::Agent => ("AGENT", theme::ACCENT),
+                        LogLevel::Error => ("ERR", theme::ERROR),
+                        LogLevel::Success => ("OK", theme::SUCCESS),
+                        LogLevel::Thought => ("THINK", theme::TEXT_MUTED),
+                    };
+
+                    ui.label(RichText::new(level_text).color(level_color).small().monospace());
+                });
+
+                // Message, rendered as Markdown so headings, emphasis, and
+                // fenced code blocks from agent/orchestrator output show
+                // up structured rather than as one flat line.
+                let msg_color = match entry.level {
+                    LogLevel::Error => theme::ERROR,
+                    LogLevel::Success => theme::SUCCESS,
+                    LogLevel::Agent => theme::AGENT_ACTIVE,
+                    LogLevel::Thought => theme::TEXT_SECONDARY,
+                    _ => theme::TEXT_PRIMARY,
+                };
+                let italic = entry.level == LogLevel::Thought;
+                crate::ui::markdown::show(ui, &entry.message, msg_color, italic);
+            });
+        })
+        .response
 }
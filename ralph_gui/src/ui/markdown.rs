@@ -0,0 +1,102 @@
+//! Renders `crate::markdown`-parsed blocks with egui widgets
+//!
+//! Used by `ui::logs` and the Thinking panel so agent output and
+//! structured orchestrator plans get headings, emphasis, and
+//! syntax-highlighted, copyable code blocks instead of one truncated
+//! line of flat `RichText`.
+
+use egui::{Color32, RichText};
+use crate::markdown::{self, Block, Span, TokenKind};
+use crate::theme;
+
+/// Render `text` as Markdown into `ui`, coloring plain prose with
+/// `base_color` (the same color `ui::logs` would otherwise have given
+/// the raw message, e.g. per-`LogLevel`). `default_italic` reproduces
+/// the whole-message italics `ui::logs` used to apply to `Thought`
+/// entries, for text that never uses `*italic*` markers of its own.
+pub fn show(ui: &mut egui::Ui, text: &str, base_color: Color32, default_italic: bool) {
+    for block in markdown::parse(text) {
+        match block {
+            Block::Heading(level, spans) => {
+                let size = match level {
+                    1 => 18.0,
+                    2 => 16.0,
+                    _ => 14.0,
+                };
+                ui.horizontal_wrapped(|ui| {
+                    for span in &spans {
+                        ui.label(styled_text(span, base_color, default_italic).size(size).strong());
+                    }
+                });
+            }
+            Block::Paragraph(spans) => {
+                ui.horizontal_wrapped(|ui| {
+                    for span in &spans {
+                        ui.add(egui::Label::new(styled_text(span, base_color, default_italic)).selectable(true));
+                    }
+                });
+            }
+            Block::ListItem(spans) => {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(RichText::new("•").color(theme::TEXT_MUTED));
+                    for span in &spans {
+                        ui.add(egui::Label::new(styled_text(span, base_color, default_italic)).selectable(true));
+                    }
+                });
+            }
+            Block::CodeBlock { language, lines } => show_code_block(ui, language, &lines),
+        }
+    }
+}
+
+fn styled_text(span: &Span, base_color: Color32, default_italic: bool) -> RichText {
+    let mut text = if span.code {
+        RichText::new(&span.text).monospace().background_color(theme::BG_INPUT).color(theme::SUCCESS)
+    } else {
+        RichText::new(&span.text).color(base_color)
+    };
+    if span.bold {
+        text = text.strong();
+    }
+    if span.italic || default_italic {
+        text = text.italics();
+    }
+    text
+}
+
+fn show_code_block(ui: &mut egui::Ui, language: Option<String>, lines: &[Vec<markdown::CodeToken>]) {
+    egui::Frame::default()
+        .fill(theme::BG_INPUT)
+        .corner_radius(egui::CornerRadius::same(6))
+        .inner_margin(8.0)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(language.as_deref().unwrap_or("text")).color(theme::TEXT_MUTED).small());
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("Copy").clicked() {
+                        let code = lines
+                            .iter()
+                            .map(|line| line.iter().map(|t| t.text.as_str()).collect::<String>())
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        ui.output_mut(|o| o.copied_text = code);
+                    }
+                });
+            });
+            for line in lines {
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    for token in line {
+                        let color = match token.kind {
+                            TokenKind::Keyword => theme::ACCENT,
+                            TokenKind::String => theme::SUCCESS,
+                            TokenKind::Comment => theme::TEXT_MUTED,
+                            TokenKind::Number => theme::AGENT_ACTIVE,
+                            TokenKind::Plain => theme::TEXT_PRIMARY,
+                        };
+                        ui.add(egui::Label::new(RichText::new(&token.text).monospace().color(color)).selectable(true));
+                    }
+                });
+            }
+        });
+}
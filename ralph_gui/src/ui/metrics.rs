@@ -1,6 +1,7 @@
 //! Metrics sidebar panel
 
 use egui::RichText;
+use crate::app::Agent;
 use crate::ralph::Metrics;
 use crate::theme;
 
@@ -21,8 +22,45 @@ pub fn show(ui: &mut egui::Ui, metrics: &Metrics) {
                 metric_row(ui, "Model", &metrics.active_model);
                 ui.add_space(4.0);
                 metric_row(ui, "Iterations", &metrics.iterations.to_string());
+                ui.add_space(4.0);
+                metric_row(ui, "Est. cost", &format_usd(metrics.estimated_cost_usd));
             });
         });
+
+    if !metrics.per_agent_tokens.is_empty() {
+        ui.add_space(8.0);
+        ui.label(RichText::new("Token usage").color(theme::TEXT_MUTED).small());
+        ui.add_space(4.0);
+
+        egui::Frame::default()
+            .fill(theme::BG_INPUT)
+            .corner_radius(egui::CornerRadius::same(8))
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.vertical(|ui| {
+                    for (i, agent) in Agent::all().iter().enumerate() {
+                        let Some(usage) = metrics.per_agent_tokens.get(agent) else { continue };
+                        if i > 0 {
+                            ui.add_space(4.0);
+                        }
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(agent.name()).color(theme::TEXT_SECONDARY).small());
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.label(
+                                    RichText::new(format!(
+                                        "{} ({})",
+                                        format_number(usage.total_tokens()),
+                                        format_usd(usage.cost_usd)
+                                    ))
+                                    .color(theme::TEXT_PRIMARY)
+                                    .small(),
+                                );
+                            });
+                        });
+                    }
+                });
+            });
+    }
 }
 
 fn metric_row(ui: &mut egui::Ui, label: &str, value: &str) {
@@ -44,6 +82,14 @@ fn format_number(n: u64) -> String {
     }
 }
 
+fn format_usd(usd: f64) -> String {
+    if usd >= 1.0 {
+        format!("${:.2}", usd)
+    } else {
+        format!("${:.4}", usd)
+    }
+}
+
 fn format_duration(ms: u64) -> String {
     if ms >= 60_000 {
         format!("{:.1}m", ms as f64 / 60_000.0)
@@ -0,0 +1,12 @@
+//! UI panels for the Ralph dashboard
+
+pub mod agent_flow;
+pub mod controls;
+pub mod graph;
+pub mod input;
+pub mod logs;
+pub mod markdown;
+pub mod metrics;
+pub mod notifications;
+pub mod search;
+pub mod tasks;
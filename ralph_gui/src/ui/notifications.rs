@@ -0,0 +1,109 @@
+//! Toast rendering for the notification subsystem
+
+use egui::{Align2, RichText};
+use crate::app::RalphApp;
+use crate::notifications::Severity;
+use crate::theme;
+
+/// How long an unpinned toast stays on screen, in seconds
+const TOAST_LIFETIME: f32 = 6.0;
+
+/// Draw auto-dismissing toasts in the bottom-right corner
+pub fn show(ctx: &egui::Context, app: &mut RalphApp) {
+    let now = app.animation_time;
+    // Expired toasts only leave the overlay; they stay in the buffer so
+    // the history panel still lists them.
+    let visible: Vec<_> = app
+        .notifications
+        .iter()
+        .filter(|n| n.pinned || now - n.shown_at <= TOAST_LIFETIME)
+        .cloned()
+        .collect();
+    let mut dismissed = Vec::new();
+    let mut toggled_pin = Vec::new();
+
+    egui::Area::new(egui::Id::new("toast_overlay"))
+        .anchor(Align2::RIGHT_BOTTOM, egui::vec2(-16.0, -16.0))
+        .show(ctx, |ui| {
+            for notification in visible.iter().rev() {
+                let (accent, label) = match notification.severity {
+                    Severity::Info => (theme::ACCENT, "INFO"),
+                    Severity::Warning => (theme::_WARNING, "WARN"),
+                    Severity::Error => (theme::ERROR, "ERROR"),
+                };
+
+                egui::Frame::default()
+                    .fill(theme::BG_CARD)
+                    .stroke(egui::Stroke::new(1.0, accent))
+                    .corner_radius(egui::CornerRadius::same(8))
+                    .inner_margin(10.0)
+                    .show(ui, |ui| {
+                        ui.set_max_width(280.0);
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(label).color(accent).small().strong());
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if ui.small_button("✕").clicked() {
+                                    dismissed.push(notification.id);
+                                }
+                                let pin_label = if notification.pinned { "📌" } else { "📍" };
+                                if ui.small_button(pin_label).clicked() {
+                                    toggled_pin.push((notification.id, !notification.pinned));
+                                }
+                            });
+                        });
+                        ui.label(RichText::new(&notification.title).color(theme::TEXT_PRIMARY).strong());
+                        ui.label(RichText::new(&notification.body).color(theme::TEXT_SECONDARY).small());
+                    });
+                ui.add_space(6.0);
+            }
+        });
+
+    for id in dismissed {
+        app.notifications.dismiss(id);
+    }
+    for (id, pinned) in toggled_pin {
+        app.notifications.pin(id, pinned);
+    }
+}
+
+/// Scrollable history of every retained notification, opened from the
+/// header bell. Unlike the toast overlay, entries here never auto-dismiss.
+pub fn show_history(ctx: &egui::Context, app: &mut RalphApp) {
+    if !app.show_notification_center {
+        return;
+    }
+
+    let mut open = true;
+    let entries: Vec<_> = app.notifications.iter().cloned().collect();
+
+    egui::Window::new("Notifications")
+        .open(&mut open)
+        .default_width(320.0)
+        .show(ctx, |ui| {
+            egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                if entries.is_empty() {
+                    ui.label(RichText::new("Nothing yet").color(theme::TEXT_MUTED).small());
+                }
+                for notification in entries.iter().rev() {
+                    let (accent, label) = match notification.severity {
+                        Severity::Info => (theme::ACCENT, "INFO"),
+                        Severity::Warning => (theme::_WARNING, "WARN"),
+                        Severity::Error => (theme::ERROR, "ERROR"),
+                    };
+
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(label).color(accent).small().strong());
+                        ui.label(RichText::new(&notification.timestamp).color(theme::TEXT_MUTED).small().monospace());
+                    });
+                    ui.label(RichText::new(&notification.title).color(theme::TEXT_PRIMARY).strong());
+                    ui.label(RichText::new(&notification.body).color(theme::TEXT_SECONDARY).small());
+                    ui.add_space(8.0);
+                }
+            });
+        });
+
+    app.notifications.mark_all_read();
+    if !open {
+        app.show_notification_center = false;
+    }
+}
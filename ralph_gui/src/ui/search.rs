@@ -0,0 +1,67 @@
+//! Log search panel
+//!
+//! Embeds `app.search_query` against `SearchIndex` and lists the top
+//! matches; clicking a result sets `app.highlighted_log` so `ui::logs`
+//! scrolls to and highlights it.
+
+use egui::RichText;
+use crate::app::RalphApp;
+use crate::ralph::LogEntry;
+use crate::theme;
+
+/// Number of results to show.
+const TOP_K: usize = 8;
+
+pub fn show(ui: &mut egui::Ui, app: &mut RalphApp) {
+    ui.heading(RichText::new("Search").color(theme::TEXT_PRIMARY));
+    ui.add_space(8.0);
+
+    ui.horizontal(|ui| {
+        ui.add_sized(
+            [ui.available_width() - 60.0, 24.0],
+            egui::TextEdit::singleline(&mut app.search_query).hint_text("Search logs..."),
+        );
+
+        if ui.button("Clear").clicked() {
+            app.search_query.clear();
+            app.highlighted_log = None;
+        }
+    });
+
+    if app.search_query.trim().is_empty() {
+        return;
+    }
+
+    ui.add_space(6.0);
+
+    let results = app.search_logs(TOP_K);
+    if results.is_empty() {
+        ui.label(RichText::new("No matches").color(theme::TEXT_MUTED).small());
+        return;
+    }
+
+    for (log_id, score) in results {
+        let Some(position) = app.log_position(log_id) else { continue };
+        let Some(entry) = app.logs.get(position) else { continue };
+
+        let snippet = snippet(entry, 80);
+        let selected = app.highlighted_log == Some(log_id);
+
+        let response = ui.selectable_label(selected, RichText::new(&snippet).small());
+        ui.label(RichText::new(format!("match {:.2}", score)).color(theme::TEXT_MUTED).small());
+
+        if response.clicked() {
+            app.highlighted_log = Some(log_id);
+        }
+        ui.add_space(2.0);
+    }
+}
+
+fn snippet(entry: &LogEntry, max_chars: usize) -> String {
+    let message: String = entry.message.chars().take(max_chars).collect();
+    if entry.message.chars().count() > max_chars {
+        format!("{message}…")
+    } else {
+        message
+    }
+}